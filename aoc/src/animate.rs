@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use aoc24::frames::{FrameFormat, FrameRecorder};
+use aoc24::input::InputSpec;
+use aoc24::registry::Registry;
+use aoc24::solution::Part;
+use aoc24::Result;
+
+pub struct AnimateOptions<'a> {
+    pub part: Part,
+    pub input: &'a InputSpec,
+    pub fps: f64,
+    pub out: Option<PathBuf>,
+    pub format: FrameFormat,
+}
+
+/// Records the simulation of a day and plays it back, or writes its frames to `out`.
+pub fn animate(registry: &Registry, day: u8, options: &AnimateOptions) -> Result<()> {
+    let recorder = record(registry, day, options.part, options.input)?;
+
+    match &options.out {
+        Some(dir) => {
+            recorder.write(dir, options.format)?;
+            aoc24::info!("Wrote {} frames to {}", recorder.len(), dir.display());
+            Ok(())
+        }
+        None => recorder.play(options.fps),
+    }
+}
+
+pub fn record(
+    registry: &Registry,
+    day: u8,
+    part: Part,
+    input: &InputSpec,
+) -> Result<FrameRecorder> {
+    let entry = registry
+        .get(day)
+        .ok_or_else(|| format!("Day {} is not registered", day))?;
+
+    let context = |error: aoc24::Error| error.with_file(input.source_name());
+
+    let contents = entry.inputs().read(input)?;
+    let parsed = entry.parse(&contents).map_err(context)?;
+
+    let mut recorder = FrameRecorder::new();
+    entry
+        .record(&parsed, part, &mut recorder)
+        .map_err(context)?;
+
+    Ok(recorder)
+}
@@ -0,0 +1,374 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc24::input::InputSpec;
+use aoc24::registry::{Entry, Registry};
+use aoc24::solution::Part;
+use aoc24::Result;
+
+use crate::run::{self, Outcome};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Solve(part) => f.pad(&format!("part{}", part)),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            _ => s
+                .strip_prefix("part")
+                .ok_or_else(|| format!("Invalid phase: {}", s))?
+                .parse()
+                .map(Phase::Solve),
+        }
+    }
+}
+
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Timings { samples }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        self.samples[self.samples.len() / 2]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+}
+
+pub struct BenchOptions<'a> {
+    pub runs: usize,
+    pub input: &'a InputSpec,
+    pub baseline: PathBuf,
+    pub save_baseline: bool,
+    /// Allowed slowdown of the median against the baseline, in percent
+    pub threshold: f64,
+}
+
+/// Median of each day, phase and input, so runs are only compared against the same input.
+type Baseline = BTreeMap<(u8, Phase, String), Duration>;
+
+pub fn default_baseline_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/aoc/bench-baseline.txt")
+}
+
+/// Timings of every phase, or why it failed. A phase that fails in any run isn't timed, since
+/// the time it took to fail says nothing about the solution.
+fn bench_day(
+    entry: &Entry,
+    input: &InputSpec,
+    contents: &str,
+    runs: usize,
+) -> Vec<(Phase, Result<Timings>)> {
+    let mut samples: BTreeMap<Phase, Result<Vec<Duration>>> = BTreeMap::new();
+
+    for _ in 0..runs {
+        let result = run::run_day(entry, input, contents, &Part::ALL);
+
+        let parse = if result.parse_failed() {
+            Err("parse failed".into())
+        } else {
+            Ok(result.parse_elapsed)
+        };
+        add_sample(&mut samples, Phase::Parse, parse);
+
+        for part in result.parts {
+            let sample = match part.outcome {
+                Outcome::Solved(_) => Ok(part.elapsed),
+                _ => Err(format!("part {} wasn't solved", part.part).into()),
+            };
+            add_sample(&mut samples, Phase::Solve(part.part), sample);
+        }
+    }
+
+    samples
+        .into_iter()
+        .map(|(phase, samples)| (phase, samples.map(Timings::new)))
+        .collect()
+}
+
+fn add_sample(
+    samples: &mut BTreeMap<Phase, Result<Vec<Duration>>>,
+    phase: Phase,
+    sample: Result<Duration>,
+) {
+    let entry = samples.entry(phase).or_insert_with(|| Ok(Vec::new()));
+
+    match (entry, sample) {
+        (Ok(durations), Ok(sample)) => durations.push(sample),
+        (entry @ Ok(_), Err(reason)) => *entry = Err(reason),
+        (Err(_), _) => {}
+    }
+}
+
+pub fn bench(registry: &Registry, day: Option<u8>, options: &BenchOptions) -> Result<()> {
+    if options.runs == 0 {
+        return Err("--runs must be at least 1".into());
+    }
+
+    let entries = match day {
+        Some(day) => vec![registry
+            .get(day)
+            .ok_or_else(|| format!("Day {} is not registered", day))?],
+        None => registry.iter().collect(),
+    };
+
+    let baseline = load_baseline(&options.baseline)?;
+    let mut medians = Baseline::new();
+    let mut regressions = 0;
+    let mut failures = 0;
+
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}",
+        "Day", "Phase", "Min", "Median", "Max", "Baseline", "Change"
+    );
+
+    for entry in entries {
+        let contents = entry.inputs().read(options.input)?;
+
+        for (phase, timings) in bench_day(entry, options.input, &contents, options.runs) {
+            let timings = match timings {
+                Ok(timings) => timings,
+                Err(error) => {
+                    failures += 1;
+                    println!("{:>3}  {:<6}  {}", entry.day(), phase, error);
+                    continue;
+                }
+            };
+
+            let key = (entry.day(), phase, options.input.to_string());
+            let previous = baseline.get(&key).copied();
+            let change = previous.and_then(|previous| percent_change(previous, timings.median()));
+            let is_regression = change.is_some_and(|change| change > options.threshold);
+
+            if is_regression {
+                regressions += 1;
+            }
+
+            println!(
+                "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}{}",
+                entry.day(),
+                phase,
+                format!("{:.1?}", timings.min()),
+                format!("{:.1?}", timings.median()),
+                format!("{:.1?}", timings.max()),
+                previous.map(|d| format!("{:.1?}", d)).unwrap_or_default(),
+                change.map(|c| format!("{:+.1}%", c)).unwrap_or_default(),
+                if is_regression { "  REGRESSION" } else { "" }
+            );
+
+            medians.insert(key, timings.median());
+        }
+    }
+
+    if options.save_baseline {
+        let mut updated = baseline;
+        updated.extend(medians);
+        save_baseline(&options.baseline, &updated)?;
+        println!("Saved baseline to {}", options.baseline.display());
+    }
+
+    if failures > 0 {
+        return Err(format!("{} phase(s) failed and weren't timed", failures).into());
+    }
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} phase(s) regressed by more than {}% against {}",
+            regressions,
+            options.threshold,
+            options.baseline.display()
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// `None` against a zero baseline, which no change can be a percentage of.
+fn percent_change(previous: Duration, current: Duration) -> Option<f64> {
+    if previous.is_zero() {
+        return None;
+    }
+
+    Some((current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0)
+}
+
+fn load_baseline(path: &Path) -> Result<Baseline> {
+    if !path.exists() {
+        return Ok(Baseline::new());
+    }
+
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    parse_baseline(&contents).map_err(|e| e.with_file(path.display()))
+}
+
+/// Parses `day phase input median_ns` lines. The input is everything between the phase and the
+/// median, since a path may contain spaces.
+fn parse_baseline(contents: &str) -> Result<Baseline> {
+    let mut baseline = Baseline::new();
+
+    for line in contents.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let invalid = || format!("invalid line `{}`", line);
+
+        let (rest, nanos) = line.rsplit_once(char::is_whitespace).ok_or_else(invalid)?;
+        let mut fields = rest.splitn(3, char::is_whitespace);
+        let (Some(day), Some(phase), Some(input)) = (fields.next(), fields.next(), fields.next())
+        else {
+            return Err(invalid().into());
+        };
+
+        let day = day.parse::<u8>().map_err(|e| e.to_string())?;
+        let phase = phase.parse::<Phase>()?;
+        let input = input.trim();
+        let nanos = nanos.parse::<u64>().map_err(|e| e.to_string())?;
+
+        if input.is_empty() {
+            return Err(invalid().into());
+        }
+
+        baseline.insert((day, phase, input.to_string()), Duration::from_nanos(nanos));
+    }
+
+    Ok(baseline)
+}
+
+fn format_baseline(baseline: &Baseline) -> String {
+    let mut contents = String::from("# day  phase  input  median_ns\n");
+
+    for ((day, phase, input), median) in baseline {
+        contents.push_str(&format!(
+            "{} {} {} {}\n",
+            day,
+            phase,
+            input,
+            median.as_nanos()
+        ));
+    }
+
+    contents
+}
+
+fn save_baseline(path: &Path, baseline: &Baseline) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+
+    fs::write(path, format_baseline(baseline))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc24::answer::Answer;
+    use aoc24::solution::Solution;
+
+    struct Failing;
+
+    impl Solution for Failing {
+        const DAY: u8 = 99;
+        const TITLE: &'static str = "Failing";
+
+        type Input = u64;
+
+        fn parse(input: &str) -> Result<u64> {
+            input.trim().parse().map_err(|e| format!("{}", e).into())
+        }
+
+        fn part1(input: &u64) -> Result<Answer> {
+            Ok((*input).into())
+        }
+
+        fn part2(_input: &u64) -> Result<Answer> {
+            Err("no answer".into())
+        }
+    }
+
+    #[test]
+    fn test_bench_day_skips_failures() {
+        let entry = Entry::new::<Failing>(env!("CARGO_MANIFEST_DIR"));
+        let phases = bench_day(&entry, &InputSpec::Example(1), "7\n", 3);
+
+        assert!(phases[0].1.is_ok());
+        assert!(phases[1].1.is_ok());
+        assert!(phases[2].1.is_err());
+
+        let phases = bench_day(&entry, &InputSpec::Example(1), "x\n", 1);
+        assert!(phases.iter().all(|(_, timings)| timings.is_err()));
+    }
+
+    #[test]
+    fn test_percent_change() {
+        let secs = Duration::from_secs;
+
+        assert_eq!(percent_change(secs(4), secs(6)), Some(50.0));
+        assert_eq!(percent_change(secs(4), secs(2)), Some(-50.0));
+        assert_eq!(percent_change(Duration::ZERO, secs(5)), None);
+        assert_eq!(percent_change(Duration::ZERO, Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let baseline = Baseline::from([
+            (
+                (6, Phase::Parse, "input".to_string()),
+                Duration::from_nanos(1200),
+            ),
+            (
+                (6, Phase::Solve(Part::Two), "example:1".to_string()),
+                Duration::from_nanos(3400),
+            ),
+            (
+                (6, Phase::Solve(Part::Two), "input".to_string()),
+                Duration::from_nanos(5600),
+            ),
+            (
+                (9, Phase::Solve(Part::One), "my inputs/d9.txt".to_string()),
+                Duration::from_nanos(7),
+            ),
+        ]);
+
+        let contents = format_baseline(&baseline) + "\n   \n";
+
+        assert_eq!(parse_baseline(&contents).unwrap(), baseline);
+        assert!(parse_baseline("6 part1 1200\n").is_err());
+        assert!(parse_baseline("6 part3 input 1200\n").is_err());
+    }
+}
@@ -0,0 +1,21 @@
+use aoc24::registry::Registry;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    d1_historian_hysteria::register(&mut registry);
+    d2_red_nosed_reports::register(&mut registry);
+    d3_mull_it_over::register(&mut registry);
+    d4_ceres_search::register(&mut registry);
+    d5_print_queue::register(&mut registry);
+    d6_guard_gallivant::register(&mut registry);
+    d7_bridge_repair::register(&mut registry);
+    d8_resonant_collinearity::register(&mut registry);
+    d9_disk_fragmenter::register(&mut registry);
+    d10_hoof_it::register(&mut registry);
+    d11_plutonian_pebbles::register(&mut registry);
+    d14_restroom_redoubt::register(&mut registry);
+    d22_monkey_market::register(&mut registry);
+
+    registry
+}
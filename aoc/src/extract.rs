@@ -0,0 +1,417 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use aoc24::answer::Answer;
+use aoc24::input::{self, InputSpec};
+use aoc24::manifest::{Manifest, MANIFEST_FILE_NAME};
+use aoc24::registry::Registry;
+use aoc24::solution::Part;
+use aoc24::Result;
+use scraper::{ElementRef, Html, Selector};
+
+/// Examples and answers found on a saved puzzle page.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Extracted {
+    /// Distinct example inputs, in the order they appear on the page.
+    pub examples: Vec<String>,
+    /// Example variant, starting at 1, and the expected answer of each part found.
+    pub answers: Vec<(u32, Part, String)>,
+}
+
+/// Parses a puzzle page saved from the browser. Every part is described in its own
+/// `article.day-desc`, and its answer is the last emphasized `<code>` in the prose. The example
+/// an answer belongs to is the nearest `<pre><code>` block before it. Pages often show renders of
+/// an example, like the guard's path drawn over the map, so a block that only differs from an
+/// earlier one where a path is drawn stands for that earlier block.
+pub fn parse_page(html: &str) -> Result<Extracted> {
+    let document = Html::parse_document(html);
+    let articles = selector("article.day-desc");
+    let blocks = selector("pre > code");
+    let elements = selector("pre > code, p code em, p em code");
+
+    let mut extracted = Extracted {
+        examples: Vec::new(),
+        answers: Vec::new(),
+    };
+    let mut seen = Vec::new();
+    let mut found_article = false;
+
+    for (article, part) in document.select(&articles).zip(Part::ALL) {
+        found_article = true;
+        let mut answer = None;
+
+        for element in article.select(&elements) {
+            if blocks.matches(&element) {
+                seen.push(text(element));
+            } else {
+                let example = seen
+                    .len()
+                    .checked_sub(1)
+                    .ok_or_else(|| format!("Part {} has no example", part))?;
+                answer = Some((example, text(element).trim().to_string()));
+            }
+        }
+
+        let Some((example, answer)) = answer else {
+            continue;
+        };
+
+        let example = original(&seen, example).ok_or_else(|| {
+            format!(
+                "The example of part {} has the shape of an earlier block but differs from it \
+                 where no path is drawn, it could be a render or a new example",
+                part
+            )
+        })?;
+        let variant = match extracted.examples.iter().position(|e| e == example) {
+            Some(i) => i,
+            None => {
+                extracted.examples.push(example.clone());
+                extracted.examples.len() - 1
+            }
+        };
+
+        extracted.answers.push((variant as u32 + 1, part, answer));
+    }
+
+    if !found_article {
+        return Err("No puzzle description found, expected an `article.day-desc`".into());
+    }
+
+    if extracted.examples.is_empty() {
+        return Err("No answers found, expected an emphasized `<code>`".into());
+    }
+
+    Ok(extracted)
+}
+
+/// Characters drawn over a map to show a path, like the guard's steps or an obstruction.
+const RENDER_MARKS: &str = "^>v<XO+-|@~";
+
+/// First block that `seen[index]` is a render of, which is the block itself if no earlier one has
+/// the same line lengths. `None` if blocks of that shape differ from it where nothing is drawn, so
+/// it could be another example just as well.
+fn original(seen: &[String], index: usize) -> Option<&String> {
+    let block = &seen[index];
+    let shape = |b: &str| b.lines().map(|l| l.chars().count()).collect::<Vec<_>>();
+    let block_shape = shape(block);
+    let mut same_shape = seen[..index]
+        .iter()
+        .filter(|b| shape(b) == block_shape)
+        .peekable();
+
+    if same_shape.peek().is_none() {
+        return Some(block);
+    }
+
+    same_shape.find(|b| is_render(b, block))
+}
+
+/// Whether `a` and `b` only differ in cells where either has a render mark.
+fn is_render(a: &str, b: &str) -> bool {
+    a.chars()
+        .zip(b.chars())
+        .all(|(x, y)| x == y || RENDER_MARKS.contains(x) || RENDER_MARKS.contains(y))
+}
+
+fn selector(selectors: &str) -> Selector {
+    Selector::parse(selectors).unwrap()
+}
+
+/// Answer as written on the page, numeric if it's a number so `007` matches `7`.
+fn page_answer(answer: &str) -> Answer {
+    Answer::number(answer).unwrap_or_else(|| Answer::Str(answer.to_string()))
+}
+
+fn text(element: ElementRef) -> String {
+    element.text().collect()
+}
+
+/// Writes the examples of a saved page into the day's crate and adds their answers to its
+/// manifest. Refuses to change existing examples or answers unless `force` is set.
+pub fn extract(registry: &Registry, day: u8, page: &Path, force: bool) -> Result<()> {
+    let entry = registry
+        .get(day)
+        .ok_or_else(|| format!("Day {} is not registered", day))?;
+    let inputs = entry.inputs();
+    let crate_dir = inputs.crate_dir();
+
+    let html = fs::read_to_string(page)
+        .map_err(|e| format!("Failed to read {}: {}", page.display(), e))?;
+    let extracted = parse_page(&html)?;
+
+    let manifest_path = crate_dir.join(MANIFEST_FILE_NAME);
+    let mut manifest = match fs::read_to_string(&manifest_path) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Failed to read {}: {}", manifest_path.display(), e).into()),
+    };
+    let existing = Manifest::parse(&manifest).map_err(|e| e.with_file(MANIFEST_FILE_NAME))?;
+
+    let mut conflicts = Vec::new();
+
+    let examples = extracted
+        .examples
+        .iter()
+        .enumerate()
+        .map(|(i, example)| {
+            (
+                crate_dir.join(input::example_file_name(i as u32 + 1)),
+                example,
+            )
+        })
+        .filter(|(path, example)| match fs::read_to_string(path) {
+            Ok(contents) if contents == **example => false,
+            Ok(_) => {
+                conflicts.push(format!("{} differs", path.display()));
+                true
+            }
+            Err(_) => true,
+        })
+        .collect::<Vec<_>>();
+
+    let answers = extracted
+        .answers
+        .iter()
+        .map(|(variant, part, answer)| (InputSpec::Example(*variant), *part, answer))
+        .filter(
+            |(input, part, answer)| match existing.expected(input, *part) {
+                Some(expected) if page_answer(answer).matches(expected) => false,
+                Some(expected) => {
+                    conflicts.push(format!(
+                        "{} part {} is {} in {}",
+                        input, part, expected, MANIFEST_FILE_NAME
+                    ));
+                    true
+                }
+                None => true,
+            },
+        )
+        .collect::<Vec<_>>();
+
+    if !conflicts.is_empty() && !force {
+        return Err(format!(
+            "The page doesn't match what's there, use --force to overwrite:\n  {}",
+            conflicts.join("\n  ")
+        )
+        .into());
+    }
+
+    for (path, example) in &examples {
+        fs::write(path, example)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        println!("Wrote {}", path.display());
+    }
+
+    for (input, part, answer) in &answers {
+        let line = format!("{:<12}{:<6}{}", input.to_string(), part.to_string(), answer);
+        manifest = replace_or_append(&manifest, input, *part, &line);
+        println!("Expecting {} part {}: {}", input, part, answer);
+    }
+
+    if !answers.is_empty() {
+        fs::write(&manifest_path, manifest)
+            .map_err(|e| format!("Failed to write {}: {}", manifest_path.display(), e))?;
+    }
+
+    if examples.is_empty() && answers.is_empty() {
+        println!("Examples and answers are up to date");
+    }
+
+    Ok(())
+}
+
+/// Manifest with the expectation of `input` and `part` replaced by `line`, or `line` added after
+/// the last example if there was none.
+fn replace_or_append(manifest: &str, input: &InputSpec, part: Part, line: &str) -> String {
+    let mut lines = manifest.lines().map(str::to_string).collect::<Vec<_>>();
+
+    let expectation = |l: &str| {
+        let mut fields = l.split_whitespace();
+        let spec = fields.next()?.parse::<InputSpec>().ok()?;
+        let part = fields.next()?.parse::<Part>().ok()?;
+        Some((spec, part))
+    };
+
+    if let Some(i) = lines
+        .iter()
+        .position(|l| expectation(l) == Some((input.clone(), part)))
+    {
+        lines[i] = line.to_string();
+    } else {
+        let after = lines
+            .iter()
+            .rposition(|l| matches!(expectation(l), Some((InputSpec::Example(_), _))))
+            .or_else(|| lines.iter().rposition(|l| l.trim_start().starts_with('#')))
+            .map_or(lines.len(), |i| i + 1);
+
+        lines.insert(after, line.to_string());
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 10: Hoof It ---</h2>
+<p>For example:</p>
+<pre><code>0123
+1234
+8765
+9876
+</code></pre>
+<p>A smaller one, <code>0&lt;1</code>, has a score of <code><em>2</em></code>.</p>
+<p>The sum of the scores is <code><em>1</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>531</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>.....0.
+..4321.
+</code></pre>
+<p>The sum of the ratings is <em><code>3</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse_page() {
+        let extracted = parse_page(PAGE).unwrap();
+
+        assert_eq!(
+            extracted.examples,
+            ["0123\n1234\n8765\n9876\n", ".....0.\n..4321.\n"]
+        );
+        assert_eq!(
+            extracted.answers,
+            [
+                (1, Part::One, "1".to_string()),
+                (2, Part::Two, "3".to_string())
+            ]
+        );
+
+        assert!(parse_page("<html><pre><code>1</code></pre></html>").is_err());
+    }
+
+    /// Several examples per part, where only the last one has the emphasized answer.
+    const LARGER_EXAMPLE_PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 10: Hoof It ---</h2>
+<pre><code>0123
+1234
+8765
+9876
+</code></pre>
+<p>This trailhead has a score of <code>1</code>.</p>
+<pre><code>...0...
+...1...
+6543456
+</code></pre>
+<p>This one has a score of <code>2</code>.</p>
+<p>Here's a larger example:</p>
+<pre><code>89010123
+78121874
+87430965
+</code></pre>
+<p>The sum of the scores of all trailheads is <code><em>36</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>.....0.
+..4321.
+</code></pre>
+<p>This has a rating of <code>3</code>. Here's the larger example from before:</p>
+<pre><code>89010123
+78121874
+87430965
+</code></pre>
+<p>The sum of all ratings is <code><em>81</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    /// Renders of the example after it, which aren't examples of their own.
+    const RENDERS_PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 6: Guard Gallivant ---</h2>
+<pre><code>..#.
+....
+.^..
+</code></pre>
+<p>The guard walks:</p>
+<pre><code>..#.
+..>.
+....
+</code></pre>
+<pre><code>..#.
+.XX.
+.X..
+</code></pre>
+<p>The guard visits <code><em>3</em></code> positions.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>..#.
+.O+.
+.^..
+</code></pre>
+<p>There are <code><em>1</em></code> positions for an obstruction.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse_page_with_several_examples() {
+        let extracted = parse_page(LARGER_EXAMPLE_PAGE).unwrap();
+
+        assert_eq!(extracted.examples, ["89010123\n78121874\n87430965\n"]);
+        assert_eq!(
+            extracted.answers,
+            [
+                (1, Part::One, "36".to_string()),
+                (1, Part::Two, "81".to_string())
+            ]
+        );
+
+        let extracted = parse_page(RENDERS_PAGE).unwrap();
+
+        assert_eq!(extracted.examples, ["..#.\n....\n.^..\n"]);
+        assert_eq!(
+            extracted.answers,
+            [
+                (1, Part::One, "3".to_string()),
+                (1, Part::Two, "1".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_page_with_ambiguous_example() {
+        let page = RENDERS_PAGE.replace("..#.\n.XX.\n.X..", "..#.\n.XX.\n#X..");
+        let error = parse_page(&page).unwrap_err().to_string();
+
+        assert!(error.contains("part 1"), "{}", error);
+    }
+
+    #[test]
+    fn test_page_answer() {
+        assert!(page_answer("007").matches("7"));
+        assert!(page_answer("1,2,3").matches("1,2,3"));
+        assert!(!page_answer("36").matches("81"));
+    }
+
+    #[test]
+    fn test_replace_or_append() {
+        let manifest = "# input     part  answer\nexample:1   1     1\ninput       1     531\n";
+
+        assert_eq!(
+            replace_or_append(manifest, &InputSpec::Example(1), Part::Two, "example:1   2     16"),
+            "# input     part  answer\nexample:1   1     1\nexample:1   2     16\ninput       1     531\n"
+        );
+        assert_eq!(
+            replace_or_append(
+                manifest,
+                &InputSpec::Example(1),
+                Part::One,
+                "example:1   1     2"
+            ),
+            "# input     part  answer\nexample:1   1     2\ninput       1     531\n"
+        );
+    }
+}
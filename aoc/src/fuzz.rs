@@ -0,0 +1,206 @@
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+use aoc24::input::InputSpec;
+use aoc24::registry::{Entry, Registry};
+use aoc24::Result;
+use sha2::{Digest, Sha256};
+
+use crate::run;
+
+/// Values spliced over numbers in the seeds, chosen to provoke overflows and empty ranges.
+const NUMBERS: [&str; 8] = [
+    "0",
+    "-1",
+    "1",
+    "2147483648",
+    "-9223372036854775809",
+    "18446744073709551616",
+    "99999999999999999999999",
+    "",
+];
+
+/// Bytes inserted into the seeds, besides the ones the seeds already use.
+const BYTES: &[u8] = b"\n\r\t -,:|=#.0123456789";
+
+pub struct FuzzOptions {
+    pub iterations: u64,
+    pub seed: u64,
+    pub out: PathBuf,
+}
+
+pub fn default_out_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/aoc/fuzz")
+}
+
+/// Feeds mutated copies of each day's inputs to its parser and keeps every input that panics.
+/// Runs without any fuzzing toolchain, so it works offline and on stable.
+pub fn fuzz(registry: &Registry, day: Option<u8>, options: &FuzzOptions) -> Result<()> {
+    let entries = match day {
+        Some(day) => vec![registry
+            .get(day)
+            .ok_or_else(|| format!("Day {} is not registered", day))?],
+        None => registry.iter().collect(),
+    };
+
+    // The panics are reported below, the default hook would print each of them with a backtrace
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let crashes = entries
+        .iter()
+        .map(|entry| fuzz_day(entry, options))
+        .collect::<Result<Vec<_>>>();
+    panic::set_hook(hook);
+
+    let crashes = crashes?.into_iter().flatten().collect::<Vec<_>>();
+
+    if crashes.is_empty() {
+        println!("No panics in {} iterations per day", options.iterations);
+        return Ok(());
+    }
+
+    for crash in &crashes {
+        println!("{}", crash);
+    }
+
+    Err(format!(
+        "{} input(s) made a parser panic, saved to {}",
+        crashes.len(),
+        options.out.display()
+    )
+    .into())
+}
+
+fn fuzz_day(entry: &Entry, options: &FuzzOptions) -> Result<Vec<String>> {
+    let inputs = entry.inputs();
+    let seeds = inputs
+        .examples()
+        .into_iter()
+        .map(InputSpec::Example)
+        .chain([InputSpec::Real])
+        .filter_map(|spec| inputs.read(&spec).ok())
+        .map(String::into_bytes)
+        .collect::<Vec<_>>();
+
+    if seeds.is_empty() {
+        aoc24::warn!("day {} has no inputs to fuzz", entry.day());
+        return Ok(Vec::new());
+    }
+
+    let mut rng = Rng::new(options.seed ^ entry.day() as u64);
+    let mut crashes = Vec::new();
+
+    for _ in 0..options.iterations {
+        let input = mutate(&mut rng, &seeds);
+        let input = String::from_utf8_lossy(&input);
+
+        if let Err(message) = run::catch_panic(|| entry.parse(&input)) {
+            let hash = format!("{:x}", Sha256::digest(input.as_bytes()));
+            let path = options
+                .out
+                .join(format!("day{}-{}.txt", entry.day(), &hash[..12]));
+
+            fs::create_dir_all(&options.out)?;
+            fs::write(&path, input.as_bytes())?;
+
+            crashes.push(format!(
+                "day {}: {} ({})",
+                entry.day(),
+                message,
+                path.display()
+            ));
+        }
+    }
+
+    aoc24::info!(
+        "day {}: {} iterations, {} panics",
+        entry.day(),
+        options.iterations,
+        crashes.len()
+    );
+
+    Ok(crashes)
+}
+
+/// Copy of a random seed with a few random edits.
+fn mutate(rng: &mut Rng, seeds: &[Vec<u8>]) -> Vec<u8> {
+    let mut input = seeds[rng.below(seeds.len())].clone();
+
+    for _ in 0..=rng.below(4) {
+        let at = rng.below(input.len() + 1);
+
+        match rng.below(6) {
+            0 if at < input.len() => input[at] = BYTES[rng.below(BYTES.len())],
+            1 => input.insert(at, BYTES[rng.below(BYTES.len())]),
+            2 => {
+                let end = (at + rng.below(16)).min(input.len());
+                input.drain(at..end);
+            }
+            3 => input.truncate(at),
+            4 => {
+                let other = &seeds[rng.below(seeds.len())];
+                let from = rng.below(other.len() + 1);
+                let end = (from + rng.below(64)).min(other.len());
+                input.splice(at..at, other[from..end].iter().copied());
+            }
+            _ => {
+                // Replaces the number around `at`, if there is one
+                let start = (0..at)
+                    .rev()
+                    .find(|&i| !input[i].is_ascii_digit())
+                    .map_or(0, |i| i + 1);
+                let end = (at..input.len())
+                    .find(|&i| !input[i].is_ascii_digit())
+                    .unwrap_or(input.len());
+                let number = NUMBERS[rng.below(NUMBERS.len())].bytes();
+                input.splice(start..end, number);
+            }
+        }
+    }
+
+    input
+}
+
+/// Xorshift generator, so a run can be repeated from its seed without extra dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n.max(1) as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutate() {
+        let seeds = vec![b"190: 10 19\n3267: 81 40 27\n".to_vec()];
+        let mut rng = Rng::new(7);
+
+        let inputs = (0..100)
+            .map(|_| mutate(&mut rng, &seeds))
+            .collect::<Vec<_>>();
+
+        assert!(inputs.iter().any(|input| *input != seeds[0]));
+        assert_eq!(
+            inputs,
+            (0..100)
+                .scan(Rng::new(7), |rng, _| Some(mutate(rng, &seeds)))
+                .collect::<Vec<_>>()
+        );
+    }
+}
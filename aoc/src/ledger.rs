@@ -0,0 +1,110 @@
+use aoc24::ledger::{Check, Ledger, Status, Verdict};
+use aoc24::registry::Registry;
+use aoc24::solution::Part;
+use aoc24::Result;
+use clap::ValueEnum;
+
+use crate::run::DayResult;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Hint {
+    Low,
+    High,
+}
+
+pub fn describe(check: Option<&Check>) -> String {
+    match check.map(|c| &c.status) {
+        Some(Status::Confirmed) => " (confirmed)".to_string(),
+        Some(Status::Changed { confirmed }) => format!(" (changed, confirmed {})", confirmed),
+        Some(Status::Drifted { previous }) => format!(" (changed, was {})", previous),
+        Some(Status::Unconfirmed) | None => String::new(),
+    }
+}
+
+pub fn marker(check: Option<&Check>) -> &'static str {
+    match check.map(|c| &c.status) {
+        Some(Status::Confirmed) => " \u{2713}",
+        Some(Status::Changed { .. }) => " \u{2717}",
+        Some(Status::Drifted { .. }) => " ~",
+        Some(Status::Unconfirmed) | None => "",
+    }
+}
+
+pub fn print_warnings(result: &DayResult) {
+    for part in &result.parts {
+        for warning in part.ledger.iter().flat_map(|check| &check.warnings) {
+            aoc24::warn!("day {} {}", result.day, warning);
+        }
+    }
+}
+
+pub fn show(registry: &Registry, day: u8, part: Option<Part>) -> Result<()> {
+    let entry = registry
+        .get(day)
+        .ok_or_else(|| format!("Day {} is not registered", day))?;
+
+    let ledger = Ledger::load(entry.dir())?;
+    let parts = part.map(|p| vec![p]).unwrap_or_else(|| Part::ALL.to_vec());
+
+    println!("Day {}: {}", entry.day(), entry.title());
+
+    for part in parts {
+        for submission in ledger.submissions(part) {
+            println!(
+                "Part {}  {:<10} {}",
+                part, submission.verdict, submission.answer
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub fn record(
+    registry: &Registry,
+    day: u8,
+    part: Part,
+    verdict: Verdict,
+    answer: &str,
+) -> Result<()> {
+    let entry = registry
+        .get(day)
+        .ok_or_else(|| format!("Day {} is not registered", day))?;
+
+    let mut ledger = Ledger::load(entry.dir())?;
+
+    if verdict == Verdict::Confirmed {
+        if let Some(confirmed) = ledger.confirmed(part) {
+            if confirmed != answer {
+                aoc24::warn!(
+                    "day {} part {} was already confirmed as {}",
+                    day,
+                    part,
+                    confirmed
+                );
+            }
+        }
+    }
+
+    ledger.record(part, verdict, answer)?;
+    ledger.save()?;
+
+    println!(
+        "Recorded day {} part {} {} as {} in {}",
+        day,
+        part,
+        answer,
+        verdict,
+        ledger.path().display()
+    );
+
+    Ok(())
+}
+
+pub fn verdict(hint: Option<Hint>) -> Verdict {
+    match hint {
+        Some(Hint::Low) => Verdict::TooLow,
+        Some(Hint::High) => Verdict::TooHigh,
+        None => Verdict::Wrong,
+    }
+}
@@ -0,0 +1,351 @@
+mod animate;
+mod bench;
+mod days;
+mod extract;
+mod fuzz;
+mod ledger;
+mod report;
+mod run;
+mod scaffold;
+mod stepper;
+mod worker;
+
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+use aoc24::frames::FrameFormat;
+use aoc24::input::InputSpec;
+use aoc24::ledger::Verdict;
+use aoc24::log;
+use aoc24::parallel;
+use aoc24::solution::Part;
+use clap::{ArgAction, Args, Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Show more diagnostic output, repeat for more detail (see also AOC_LOG)
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+
+    /// Show less output, repeat to silence warnings as well
+    #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "verbose")]
+    quiet: u8,
+
+    /// Worker threads for parallel searches (needs the `parallel` feature)
+    #[arg(short = 'j', long, global = true)]
+    threads: Option<usize>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every registered day with --all
+    Run(RunArgs),
+    /// List the registered days
+    List,
+    /// Show or record submitted answers of a day
+    Ledger(LedgerArgs),
+    /// Time the parse and solve phases of one or every day
+    Bench(BenchArgs),
+    /// Create the crate of a new day and register it everywhere
+    New(NewArgs),
+    /// Replay the simulation of a day in the terminal or write it out as frames
+    Animate(AnimateArgs),
+    /// Step through the simulation of a day interactively
+    Step(StepArgs),
+    /// Feed mutated inputs to the parsers and report any that panic
+    Fuzz(FuzzArgs),
+    /// Save the examples and answers of a saved puzzle page into a day's crate
+    Extract(ExtractArgs),
+    /// Solve one part of the input on stdin, for the runner's --timeout and --memory
+    #[command(hide = true)]
+    Worker(WorkerArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Only run this part (1 or 2)
+    #[arg(short, long, conflicts_with = "all")]
+    part: Option<Part>,
+
+    /// `input`, `example`, `example:N`, a file path, or `-` for stdin
+    #[arg(short, long, default_value = "input")]
+    input: InputSpec,
+
+    /// Run every registered day and print a summary table
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// Also write the results to this file, as JSON or CSV depending on the extension
+    #[arg(long, value_name = "PATH")]
+    report: Vec<report::ReportTarget>,
+
+    /// Stop a part after this many seconds and report it as TIMEOUT
+    #[arg(long, value_name = "SECONDS")]
+    timeout: Option<f64>,
+
+    /// Cap the memory of a part, in MiB, and report it as OOM when it runs out
+    #[arg(long, value_name = "MIB")]
+    memory: Option<u64>,
+}
+
+#[derive(Args)]
+struct WorkerArgs {
+    #[arg(long)]
+    day: u8,
+
+    #[arg(long)]
+    part: Part,
+
+    /// Name of the input in diagnostics, the input itself is read from stdin
+    #[arg(long)]
+    input: InputSpec,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark (defaults to every registered day)
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Number of timed runs per day
+    #[arg(short, long, default_value_t = 10)]
+    runs: usize,
+
+    /// `input`, `example` or `example:N`
+    #[arg(short, long, default_value = "input")]
+    input: InputSpec,
+
+    /// Baseline file to compare against (defaults to target/aoc/bench-baseline.txt)
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Store the medians of this run in the baseline file
+    #[arg(long)]
+    save_baseline: bool,
+
+    /// Slowdown of the median, in percent, that counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct AnimateArgs {
+    /// Day to animate
+    #[arg(short, long)]
+    day: u8,
+
+    /// Part whose simulation to record (1 or 2)
+    #[arg(short, long, default_value = "1")]
+    part: Part,
+
+    /// `input`, `example`, `example:N`, a file path, or `-` for stdin
+    #[arg(short, long, default_value = "example")]
+    input: InputSpec,
+
+    /// Frames per second when playing in the terminal
+    #[arg(long, default_value_t = 10.0)]
+    fps: f64,
+
+    /// Write the frames to this directory instead of playing them
+    #[arg(long, value_name = "DIR")]
+    out: Option<PathBuf>,
+
+    /// Format of the written frames, `text` or `ppm`
+    #[arg(long, default_value = "text", requires = "out")]
+    format: FrameFormat,
+}
+
+#[derive(Args)]
+struct StepArgs {
+    /// Day to step through
+    #[arg(short, long)]
+    day: u8,
+
+    /// Part whose simulation to record (1 or 2)
+    #[arg(short, long, default_value = "1")]
+    part: Part,
+
+    /// `input`, `example`, `example:N`, a file path, or `-` for stdin
+    #[arg(short, long, default_value = "example")]
+    input: InputSpec,
+}
+
+#[derive(Args)]
+struct FuzzArgs {
+    /// Day to fuzz (defaults to every registered day)
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Mutated inputs to try per day
+    #[arg(short = 'n', long, default_value_t = 10_000)]
+    iterations: u64,
+
+    /// Seed of the mutations, the same seed tries the same inputs
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    /// Directory for inputs that panic (defaults to target/aoc/fuzz)
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ExtractArgs {
+    /// Day the page describes
+    #[arg(short, long)]
+    day: u8,
+
+    /// Puzzle page saved from the browser, as HTML
+    page: PathBuf,
+
+    /// Overwrite examples and answers that differ from the page
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day of the puzzle
+    day: u8,
+
+    /// Puzzle title in kebab-case, e.g. garden-groups
+    slug: String,
+}
+
+#[derive(Args)]
+struct LedgerArgs {
+    /// Day of the submission
+    #[arg(short, long)]
+    day: u8,
+
+    /// Part of the submission (1 or 2)
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    #[command(subcommand)]
+    action: Option<LedgerAction>,
+}
+
+#[derive(Subcommand)]
+enum LedgerAction {
+    /// Record an accepted answer
+    Confirm { answer: String },
+    /// Record a rejected answer, with the hint given for it
+    Reject {
+        answer: String,
+        #[arg(long, value_enum)]
+        hint: Option<ledger::Hint>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    log::set_level(log::env_level().shift(cli.verbose as i32 - cli.quiet as i32));
+
+    if let Some(threads) = cli.threads {
+        if let Err(error) = parallel::set_threads(threads) {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    }
+
+    let registry = days::registry();
+
+    let result = match cli.command {
+        Command::Run(args) => {
+            let limits = worker::Limits {
+                timeout: args.timeout.map(Duration::from_secs_f64),
+                memory_mib: args.memory,
+            };
+
+            if args.all {
+                run::run_all(&registry, &args.input, &args.report, &limits)
+            } else {
+                run::run_single(
+                    &registry,
+                    args.day.unwrap(),
+                    args.part,
+                    &args.input,
+                    &args.report,
+                    &limits,
+                )
+            }
+        }
+        Command::Worker(args) => worker::serve(&registry, args.day, args.part, &args.input),
+        Command::Ledger(args) => match args.action {
+            None => ledger::show(&registry, args.day, args.part),
+            Some(action) => {
+                let (verdict, answer) = match action {
+                    LedgerAction::Confirm { answer } => (Verdict::Confirmed, answer),
+                    LedgerAction::Reject { answer, hint } => (ledger::verdict(hint), answer),
+                };
+
+                match args.part {
+                    Some(part) => ledger::record(&registry, args.day, part, verdict, &answer),
+                    None => Err("--part is required to record an answer".into()),
+                }
+            }
+        },
+        Command::Bench(args) => {
+            let options = bench::BenchOptions {
+                runs: args.runs,
+                input: &args.input,
+                baseline: args.baseline.unwrap_or_else(bench::default_baseline_path),
+                save_baseline: args.save_baseline,
+                threshold: args.threshold,
+            };
+
+            bench::bench(&registry, args.day, &options)
+        }
+        Command::Animate(args) => {
+            let options = animate::AnimateOptions {
+                part: args.part,
+                input: &args.input,
+                fps: args.fps,
+                out: args.out,
+                format: args.format,
+            };
+
+            animate::animate(&registry, args.day, &options)
+        }
+        Command::Step(args) => animate::record(&registry, args.day, args.part, &args.input)
+            .and_then(|recorder| {
+                let title = format!("Day {} part {}", args.day, args.part);
+                stepper::run(&recorder, &title)
+            }),
+        Command::Fuzz(args) => {
+            let options = fuzz::FuzzOptions {
+                iterations: args.iterations,
+                seed: args.seed,
+                out: args.out.unwrap_or_else(fuzz::default_out_dir),
+            };
+
+            fuzz::fuzz(&registry, args.day, &options)
+        }
+        Command::Extract(args) => extract::extract(&registry, args.day, &args.page, args.force),
+        Command::New(args) => {
+            scaffold::new_day(&registry, &scaffold::workspace_root(), args.day, &args.slug)
+        }
+        Command::List => {
+            for entry in registry.iter() {
+                println!("{:>3}  {}", entry.day(), entry.title());
+            }
+
+            Ok(())
+        }
+    };
+
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,260 @@
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc24::answer::Answer;
+use aoc24::input::InputSpec;
+use aoc24::registry::Entry;
+use aoc24::solution::Part;
+use aoc24::Result;
+use serde::Serialize;
+
+use crate::run::{DayResult, Outcome};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+/// A report file requested with `--report`, its format taken from the extension.
+#[derive(Debug, Clone)]
+pub struct ReportTarget {
+    pub path: PathBuf,
+    pub format: Format,
+}
+
+impl FromStr for ReportTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let path = PathBuf::from(s);
+
+        let format = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Format::Json,
+            Some("csv") => Format::Csv,
+            _ => return Err(format!("{} should end in .json or .csv", s)),
+        };
+
+        Ok(ReportTarget { path, format })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Unsolved,
+    Panic,
+    Timeout,
+    Oom,
+    /// The part returned an error, its input didn't parse, or the day didn't run at all, e.g.
+    /// because its input is missing
+    Error,
+}
+
+/// One row of a report: the outcome of a single part of a day.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub title: &'static str,
+    pub part: u8,
+    pub input: String,
+    pub input_sha256: Option<String>,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    /// Allocations while solving, only counted with the `alloc-stats` feature
+    pub alloc_count: Option<u64>,
+    pub alloc_bytes: Option<u64>,
+    pub peak_bytes: Option<u64>,
+    /// Seconds since the Unix epoch when the report was written
+    pub timestamp: u64,
+}
+
+pub fn records(result: &DayResult, input: &InputSpec) -> Vec<Record> {
+    let timestamp = timestamp();
+
+    result
+        .parts
+        .iter()
+        .map(|part| {
+            let (status, answer, error) = match &part.outcome {
+                Outcome::Failed(error) => (Status::Error, None, Some(error.to_string())),
+                Outcome::Solved(Answer::Unsolved) => (Status::Unsolved, None, None),
+                Outcome::Solved(answer) => (Status::Ok, Some(answer.to_string()), None),
+                Outcome::Panicked(message) => (Status::Panic, None, Some(message.clone())),
+                Outcome::TimedOut(elapsed) => (
+                    Status::Timeout,
+                    None,
+                    Some(format!("timed out after {:.1?}", elapsed)),
+                ),
+                Outcome::OutOfMemory(memory_mib) => (
+                    Status::Oom,
+                    None,
+                    Some(format!("ran out of memory over {} MiB", memory_mib)),
+                ),
+            };
+
+            Record {
+                day: result.day,
+                title: result.title,
+                part: part.part.number(),
+                input: input.to_string(),
+                input_sha256: Some(result.input_sha256.clone()),
+                status,
+                answer,
+                error,
+                parse_ns: Some(result.parse_elapsed.as_nanos() as u64),
+                solve_ns: Some(part.elapsed.as_nanos() as u64),
+                alloc_count: part.allocs.map(|allocs| allocs.allocations),
+                alloc_bytes: part.allocs.map(|allocs| allocs.bytes),
+                peak_bytes: part.allocs.map(|allocs| allocs.peak_bytes),
+                timestamp,
+            }
+        })
+        .collect()
+}
+
+pub fn error_records(entry: &Entry, input: &InputSpec, error: &str) -> Vec<Record> {
+    let timestamp = timestamp();
+
+    Part::ALL
+        .iter()
+        .map(|part| Record {
+            day: entry.day(),
+            title: entry.title(),
+            part: part.number(),
+            input: input.to_string(),
+            input_sha256: None,
+            status: Status::Error,
+            answer: None,
+            error: Some(error.to_string()),
+            parse_ns: None,
+            solve_ns: None,
+            alloc_count: None,
+            alloc_bytes: None,
+            peak_bytes: None,
+            timestamp,
+        })
+        .collect()
+}
+
+fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn write_all(targets: &[ReportTarget], records: &[Record]) -> Result<()> {
+    for target in targets {
+        write(target, records)?;
+        aoc24::info!("Wrote report to {}", target.path.display());
+    }
+
+    Ok(())
+}
+
+fn write(target: &ReportTarget, records: &[Record]) -> Result<()> {
+    let contents = match target.format {
+        Format::Json => to_json(records)?,
+        Format::Csv => to_csv(records)?,
+    };
+
+    if let Some(dir) = target
+        .path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+    {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+
+    fs::write(&target.path, contents)
+        .map_err(|e| format!("Failed to write {}: {}", target.path.display(), e).into())
+}
+
+fn to_json(records: &[Record]) -> Result<String> {
+    serde_json::to_string_pretty(records)
+        .map(|json| json + "\n")
+        .map_err(|e| format!("Failed to serialize report: {}", e).into())
+}
+
+fn to_csv(records: &[Record]) -> Result<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+
+    for record in records {
+        writer
+            .serialize(record)
+            .map_err(|e| format!("Failed to serialize report: {}", e))?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| format!("Failed to serialize report: {}", e))?;
+
+    String::from_utf8(bytes).map_err(|e| format!("Failed to serialize report: {}", e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(status: Status) -> Record {
+        Record {
+            day: 1,
+            title: "Historian Hysteria",
+            part: 2,
+            input: "example:1".to_string(),
+            input_sha256: Some("ab12".to_string()),
+            status,
+            answer: (status == Status::Ok).then(|| "31".to_string()),
+            error: (status != Status::Ok).then(|| "boom, \"quoted\"".to_string()),
+            parse_ns: Some(1500),
+            solve_ns: Some(250),
+            alloc_count: Some(3),
+            alloc_bytes: Some(96),
+            peak_bytes: Some(64),
+            timestamp: 1733011200,
+        }
+    }
+
+    #[test]
+    fn test_target_format() {
+        assert_eq!(
+            "out/run.json".parse::<ReportTarget>().unwrap().format,
+            Format::Json
+        );
+        assert_eq!(
+            "run.csv".parse::<ReportTarget>().unwrap().format,
+            Format::Csv
+        );
+        assert!("run.txt".parse::<ReportTarget>().is_err());
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = to_csv(&[record(Status::Ok), record(Status::Panic)]).unwrap();
+
+        assert_eq!(
+            csv,
+            "day,title,part,input,input_sha256,status,answer,error,parse_ns,solve_ns,alloc_count,alloc_bytes,peak_bytes,timestamp\n\
+             1,Historian Hysteria,2,example:1,ab12,ok,31,,1500,250,3,96,64,1733011200\n\
+             1,Historian Hysteria,2,example:1,ab12,panic,,\"boom, \"\"quoted\"\"\",1500,250,3,96,64,1733011200\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&to_json(&[record(Status::Panic)]).unwrap()).unwrap();
+
+        assert_eq!(json[0]["status"], "panic");
+        assert_eq!(json[0]["answer"], serde_json::Value::Null);
+        assert_eq!(json[0]["solve_ns"], 250);
+    }
+}
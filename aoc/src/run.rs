@@ -0,0 +1,353 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use aoc24::answer::Answer;
+use aoc24::input::InputSpec;
+use aoc24::ledger::{Check, Ledger};
+use aoc24::memory::{self, AllocStats};
+use aoc24::parallel;
+use aoc24::registry::{Entry, Registry};
+use aoc24::solution::Part;
+use aoc24::{Error, Result};
+use sha2::{Digest, Sha256};
+
+use crate::ledger;
+use crate::report::{self, ReportTarget};
+use crate::worker::{self, Limits};
+
+pub enum Outcome {
+    Solved(Answer),
+    Failed(Error),
+    Panicked(String),
+    /// Killed by the runner after running this long.
+    TimedOut(Duration),
+    /// Ran out of memory under a cap of this many MiB.
+    OutOfMemory(u64),
+}
+
+impl Outcome {
+    pub fn answer(&self) -> Option<&Answer> {
+        match self {
+            Outcome::Solved(answer) => Some(answer),
+            _ => None,
+        }
+    }
+}
+
+pub struct PartResult {
+    pub part: Part,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    /// Only counted with the `alloc-stats` feature.
+    pub allocs: Option<AllocStats>,
+    pub ledger: Option<Check>,
+}
+
+pub struct DayResult {
+    pub day: u8,
+    pub title: &'static str,
+    pub input_sha256: String,
+    pub parse_elapsed: Duration,
+    pub parse_error: Option<Error>,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    pub fn total_elapsed(&self) -> Duration {
+        self.parse_elapsed
+            + self
+                .parts
+                .iter()
+                .map(|result| result.elapsed)
+                .sum::<Duration>()
+    }
+
+    /// Whether `parse` returned an error or panicked, which fails every part.
+    pub fn parse_failed(&self) -> bool {
+        self.parse_error.is_some()
+            || self.parts.iter().any(|part| {
+                matches!(&part.outcome, Outcome::Panicked(message) if message.starts_with(PARSE_PANIC))
+            })
+    }
+}
+
+/// Prefix of the panic message recorded for every part when `parse` panics.
+const PARSE_PANIC: &str = "parse: ";
+
+/// Runs the given parts of a day. An error or panic in `parse` or `solve` is caught and recorded
+/// as the outcome of the affected parts, so one broken day doesn't take down the others.
+pub fn run_day(entry: &Entry, spec: &InputSpec, input: &str, parts: &[Part]) -> DayResult {
+    let start = Instant::now();
+    let parsed = catch_panic(|| entry.parse(input))
+        .map(|parsed| parsed.map_err(|e| e.with_file(spec.source_name())));
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+
+            let (outcome, allocs) = match &parsed {
+                Ok(Ok(parsed)) => {
+                    let (solved, allocs) =
+                        memory::measure(|| catch_panic(|| entry.solve(parsed, part)));
+
+                    let outcome = match solved {
+                        Ok(Ok(answer)) => Outcome::Solved(answer),
+                        Ok(Err(error)) => Outcome::Failed(error),
+                        Err(message) => Outcome::Panicked(message),
+                    };
+
+                    (outcome, allocs)
+                }
+                Ok(Err(error)) => (Outcome::Failed(error.clone()), None),
+                Err(message) => (
+                    Outcome::Panicked(format!("{}{}", PARSE_PANIC, message)),
+                    None,
+                ),
+            };
+
+            PartResult {
+                part,
+                outcome,
+                elapsed: start.elapsed(),
+                allocs,
+                ledger: None,
+            }
+        })
+        .collect();
+
+    DayResult {
+        day: entry.day(),
+        title: entry.title(),
+        input_sha256: format!("{:x}", Sha256::digest(input)),
+        parse_elapsed,
+        parse_error: parsed.ok().and_then(|parsed| parsed.err()),
+        parts,
+    }
+}
+
+/// Runs the parts in workers if any limit is set, or in this process otherwise.
+fn run_limited(
+    entry: &Entry,
+    spec: &InputSpec,
+    input: &str,
+    parts: &[Part],
+    limits: &Limits,
+) -> Result<DayResult> {
+    if limits.is_set() {
+        worker::run_day(entry, spec, input, parts, limits)
+    } else {
+        Ok(run_day(entry, spec, input, parts))
+    }
+}
+
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> std::result::Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(&*payload))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Compares the answers against the day's ledger and the last run, which only cover the real
+/// input. Ledger problems are warnings, they never stop a run.
+fn check_ledger(entry: &Entry, result: &mut DayResult) {
+    let mut ledger = match Ledger::load(entry.dir()) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            aoc24::warn!("day {} ledger not checked: {}", entry.day(), e);
+            return;
+        }
+    };
+
+    for part in &mut result.parts {
+        if let Some(answer) = part.outcome.answer() {
+            part.ledger = Some(ledger.check(part.part, answer));
+
+            if matches!(answer, Answer::Unsolved) {
+                continue;
+            }
+
+            if let Err(e) = ledger.remember(part.part, answer) {
+                aoc24::warn!(
+                    "day {} part {} not remembered: {}",
+                    entry.day(),
+                    part.part,
+                    e
+                );
+            }
+        }
+    }
+
+    if let Err(e) = ledger.save_last() {
+        aoc24::warn!("day {} last answers not saved: {}", entry.day(), e);
+    }
+}
+
+pub fn run_single(
+    registry: &Registry,
+    day: u8,
+    part: Option<Part>,
+    input: &InputSpec,
+    reports: &[ReportTarget],
+    limits: &Limits,
+) -> Result<()> {
+    let entry = registry
+        .get(day)
+        .ok_or_else(|| format!("Day {} is not registered", day))?;
+
+    let contents = entry.inputs().read(input)?;
+    let parts = part.map(|p| vec![p]).unwrap_or_else(|| Part::ALL.to_vec());
+    let mut result = run_limited(entry, input, &contents, &parts, limits)?;
+
+    if *input == InputSpec::Real {
+        check_ledger(entry, &mut result);
+    }
+
+    report::write_all(reports, &report::records(&result, input))?;
+
+    // A parse error fails every part the same way, so it's reported once
+    if let Some(error) = result.parse_error {
+        return Err(error);
+    }
+
+    println!("Day {}: {}", result.day, result.title);
+
+    for part in &result.parts {
+        match &part.outcome {
+            Outcome::Solved(answer) => println!(
+                "Solution (Part {}): {}{}",
+                part.part,
+                answer,
+                ledger::describe(part.ledger.as_ref())
+            ),
+            Outcome::Failed(error) => println!("Solution (Part {}): error: {}", part.part, error),
+            Outcome::Panicked(message) => {
+                println!("Solution (Part {}): panicked: {}", part.part, message)
+            }
+            Outcome::TimedOut(elapsed) => {
+                println!(
+                    "Solution (Part {}): TIMEOUT after {:.1?}",
+                    part.part, elapsed
+                )
+            }
+            Outcome::OutOfMemory(memory_mib) => println!(
+                "Solution (Part {}): OOM, over {} MiB",
+                part.part, memory_mib
+            ),
+        }
+
+        if let Some(allocs) = &part.allocs {
+            println!("  {}", allocs);
+        }
+    }
+
+    // The allocation counters are global, so parallel searches count every worker thread
+    if result.parts.iter().any(|part| part.allocs.is_some()) && parallel::threads() > 1 {
+        println!(
+            "Allocations include all {} threads and the peak is approximate, use -j 1 for exact numbers",
+            parallel::threads()
+        );
+    }
+
+    ledger::print_warnings(&result);
+
+    Ok(())
+}
+
+pub fn run_all(
+    registry: &Registry,
+    input: &InputSpec,
+    reports: &[ReportTarget],
+    limits: &Limits,
+) -> Result<()> {
+    if input.file_name().is_none() {
+        return Err(format!("--all can't read the same input {} for every day", input).into());
+    }
+
+    let mut rows = Vec::new();
+
+    for entry in registry.iter() {
+        let mut row = entry
+            .inputs()
+            .read(input)
+            .and_then(|contents| run_limited(entry, input, &contents, &Part::ALL, limits));
+
+        if let (Ok(result), InputSpec::Real) = (&mut row, input) {
+            check_ledger(entry, result);
+        }
+
+        rows.push((entry, row));
+    }
+
+    print_summary(&rows);
+
+    let mut records = Vec::new();
+
+    for (entry, row) in &rows {
+        match row {
+            Ok(result) => {
+                ledger::print_warnings(result);
+                records.extend(report::records(result, input));
+            }
+            Err(error) => records.extend(report::error_records(entry, input, &error.to_string())),
+        }
+    }
+
+    report::write_all(reports, &records)
+}
+
+fn print_summary(rows: &[(&Entry, Result<DayResult>)]) {
+    let title_width = rows
+        .iter()
+        .map(|(entry, _)| entry.title().len())
+        .max()
+        .unwrap_or(0);
+
+    println!(
+        "{:>3}  {:<title_width$}  {:>16}  {:>16}  {:>10}",
+        "Day", "Title", "Part 1", "Part 2", "Time"
+    );
+
+    for (entry, row) in rows {
+        match row {
+            Ok(result) => println!(
+                "{:>3}  {:<title_width$}  {:>16}  {:>16}  {:>10}",
+                entry.day(),
+                entry.title(),
+                answer_cell(result, Part::One),
+                answer_cell(result, Part::Two),
+                format!("{:.1?}", result.total_elapsed()),
+            ),
+            Err(error) => println!(
+                "{:>3}  {:<title_width$}  {}",
+                entry.day(),
+                entry.title(),
+                error
+            ),
+        }
+    }
+}
+
+fn answer_cell(result: &DayResult, part: Part) -> String {
+    result
+        .parts
+        .iter()
+        .find(|r| r.part == part)
+        .map(|r| match &r.outcome {
+            Outcome::Solved(answer) => format!("{}{}", answer, ledger::marker(r.ledger.as_ref())),
+            Outcome::Failed(_) => "error".to_string(),
+            Outcome::Panicked(_) => "panicked".to_string(),
+            Outcome::TimedOut(_) => "TIMEOUT".to_string(),
+            Outcome::OutOfMemory(_) => "OOM".to_string(),
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,393 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc24::input::{self, InputResolver};
+use aoc24::manifest::MANIFEST_FILE_NAME;
+use aoc24::registry::Registry;
+use aoc24::Result;
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/day/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/day/main.rs.tmpl");
+const ANSWERS_RS: &str = include_str!("../templates/day/answers.rs.tmpl");
+const ANSWERS_TXT: &str = include_str!("../templates/day/answers.txt.tmpl");
+
+/// Names derived from the day number and slug, e.g. 12 and `garden-groups`.
+struct Names {
+    day: u8,
+    crate_name: String,
+    lib_name: String,
+    type_name: String,
+    title: String,
+}
+
+impl Names {
+    fn new(day: u8, slug: &str) -> Result<Self> {
+        if !(1..=25).contains(&day) {
+            return Err(format!("Day {} is not between 1 and 25", day).into());
+        }
+
+        let is_valid = !slug.is_empty()
+            && slug
+                .split('-')
+                .all(|word| !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase()));
+
+        if !is_valid {
+            return Err(format!(
+                "Slug `{}` should be lowercase words separated by dashes, e.g. garden-groups",
+                slug
+            )
+            .into());
+        }
+
+        let crate_name = format!("d{}-{}", day, slug);
+        let words = slug.split('-').map(capitalize).collect::<Vec<String>>();
+
+        Ok(Names {
+            day,
+            lib_name: crate_name.replace('-', "_"),
+            crate_name,
+            type_name: words.concat(),
+            title: words.join(" "),
+        })
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{{crate}}", &self.crate_name)
+            .replace("{{lib}}", &self.lib_name)
+            .replace("{{type}}", &self.type_name)
+            .replace("{{title}}", &self.title)
+            .replace("{{day}}", &self.day.to_string())
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives inside the workspace")
+        .to_path_buf()
+}
+
+/// Creates the crate of a new day from the templates and wires it into the workspace, the runner
+/// and the editor's debug configurations.
+pub fn new_day(registry: &Registry, root: &Path, day: u8, slug: &str) -> Result<()> {
+    let names = Names::new(day, slug)?;
+
+    if registry.get(day).is_some() {
+        return Err(format!("Day {} is already registered", day).into());
+    }
+
+    let crate_dir = root.join(&names.crate_name);
+
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()).into());
+    }
+
+    let files = [
+        ("Cargo.toml", names.render(CARGO_TOML)),
+        ("src/lib.rs", names.render(LIB_RS)),
+        ("src/main.rs", names.render(MAIN_RS)),
+        ("tests/answers.rs", names.render(ANSWERS_RS)),
+        (MANIFEST_FILE_NAME, names.render(ANSWERS_TXT)),
+        (&input::example_file_name(1), String::new()),
+    ];
+
+    for (path, contents) in files {
+        write_file(&crate_dir.join(path), &contents)?;
+    }
+
+    edit_file(&root.join("Cargo.toml"), |s| {
+        add_workspace_member(s, &names)
+    })?;
+    edit_file(&root.join("aoc/Cargo.toml"), |s| {
+        add_runner_dependency(s, &names)
+    })?;
+    edit_file(&root.join("aoc/src/days.rs"), |s| {
+        add_registration(s, &names)
+    })?;
+    edit_file(&root.join(".vscode/launch.json"), |s| {
+        add_launch_configs(s, &names)
+    })?;
+
+    println!("Created {}", crate_dir.display());
+    println!(
+        "Paste the example into {} and its answers into {}",
+        input::example_file_name(1),
+        MANIFEST_FILE_NAME
+    );
+    println!(
+        "Save your puzzle input to {}",
+        InputResolver::new(&crate_dir).real_input_path().display()
+    );
+
+    Ok(())
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+
+    fs::write(path, contents)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e).into())
+}
+
+fn edit_file(path: &Path, edit: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    let edited = edit(&contents).map_err(|e| e.with_file(path.display()))?;
+
+    write_file(path, &edited)
+}
+
+/// Day number of a line mentioning a day crate, e.g. 7 for `d7-bridge-repair = { ... }` or
+/// `    d7_bridge_repair::register(&mut registry);`.
+fn line_day(line: &str) -> Option<u8> {
+    let rest = line
+        .trim_start()
+        .trim_start_matches('"')
+        .strip_prefix('d')?;
+    let end = rest.find(|c: char| !c.is_ascii_digit())?;
+
+    match rest[end..].chars().next() {
+        Some('-') | Some('_') => rest[..end].parse().ok(),
+        _ => None,
+    }
+}
+
+/// Inserts `new_line` among the day lines following the line `after`, keeping them ordered by day.
+/// Returns the index the line ended up at.
+fn insert_by_day(lines: &mut Vec<String>, after: &str, day: u8, new_line: String) -> Result<usize> {
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == after)
+        .ok_or_else(|| format!("no `{}` line", after))?;
+
+    let day_lines = lines
+        .iter()
+        .enumerate()
+        .skip(start)
+        .filter_map(|(i, line)| line_day(line).map(|d| (i, d)))
+        .collect::<Vec<(usize, u8)>>();
+
+    let (last, _) = *day_lines.last().ok_or("no existing day to insert after")?;
+
+    let index = day_lines
+        .iter()
+        .find(|(_, d)| *d > day)
+        .map(|(i, _)| *i)
+        .unwrap_or(last + 1);
+
+    lines.insert(index, new_line);
+
+    Ok(index)
+}
+
+fn to_lines(contents: &str) -> Vec<String> {
+    contents.lines().map(|line| line.to_string()).collect()
+}
+
+fn from_lines(lines: &[String]) -> String {
+    lines.join("\n") + "\n"
+}
+
+fn add_workspace_member(contents: &str, names: &Names) -> Result<String> {
+    let mut lines = to_lines(contents);
+    let index = insert_by_day(
+        &mut lines,
+        "members = [",
+        names.day,
+        format!("    \"{}\"", names.crate_name),
+    )?;
+
+    // The last member has no trailing comma
+    let is_last = lines.get(index + 1).map(|l| l.trim()) == Some("]");
+
+    if is_last {
+        lines[index - 1].push(',');
+    } else {
+        lines[index].push(',');
+    }
+
+    Ok(from_lines(&lines))
+}
+
+fn add_runner_dependency(contents: &str, names: &Names) -> Result<String> {
+    let mut lines = to_lines(contents);
+    let line = format!(
+        "{} = {{ path = \"../{}\" }}",
+        names.crate_name, names.crate_name
+    );
+
+    insert_by_day(&mut lines, "[dependencies]", names.day, line)?;
+
+    Ok(from_lines(&lines))
+}
+
+fn add_registration(contents: &str, names: &Names) -> Result<String> {
+    let mut lines = to_lines(contents);
+    let line = format!("    {}::register(&mut registry);", names.lib_name);
+
+    insert_by_day(
+        &mut lines,
+        "let mut registry = Registry::new();",
+        names.day,
+        line,
+    )?;
+
+    Ok(from_lines(&lines))
+}
+
+fn launch_config(names: &Names, label: &str, cargo_args: &[&str]) -> String {
+    let args = cargo_args
+        .iter()
+        .map(|arg| format!("          \"{}\"", arg))
+        .collect::<Vec<String>>()
+        .join(",\n");
+
+    format!(
+        r#"    {{
+      "type": "lldb",
+      "request": "launch",
+      "name": "{label} '{name}'",
+      "cargo": {{
+        "args": [
+{args}
+        ],
+        "filter": {{
+          "name": "{name}",
+          "kind": "bin"
+        }}
+      }},
+      "args": [],
+      "cwd": "${{workspaceFolder}}"
+    }}"#,
+        label = label,
+        name = names.crate_name,
+        args = args
+    )
+}
+
+fn add_launch_configs(contents: &str, names: &Names) -> Result<String> {
+    if contents.contains(&format!("'{}'", names.crate_name)) {
+        return Ok(contents.to_string());
+    }
+
+    let bin = format!("--bin={}", names.crate_name);
+    let package = format!("--package={}", names.crate_name);
+
+    let configs = [
+        launch_config(names, "Debug executable", &["build", &bin, &package]),
+        launch_config(
+            names,
+            "Debug unit tests in executable",
+            &["test", "--no-run", &bin, &package],
+        ),
+    ];
+
+    let end = contents
+        .rfind(']')
+        .ok_or("no configurations list to add to")?;
+    let before = contents[..end].trim_end();
+    let separator = if before.ends_with('[') { "" } else { "," };
+
+    Ok(format!(
+        "{}{}\n{}\n  {}",
+        before,
+        separator,
+        configs.join(",\n"),
+        &contents[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names() -> Names {
+        Names::new(12, "garden-groups").unwrap()
+    }
+
+    #[test]
+    fn test_names() {
+        let names = names();
+
+        assert_eq!(names.crate_name, "d12-garden-groups");
+        assert_eq!(names.lib_name, "d12_garden_groups");
+        assert_eq!(names.type_name, "GardenGroups");
+        assert_eq!(names.title, "Garden Groups");
+
+        assert!(Names::new(26, "too-late").is_err());
+        assert!(Names::new(12, "Garden_Groups").is_err());
+        assert!(Names::new(12, "garden--groups").is_err());
+    }
+
+    #[test]
+    fn test_templates_are_fully_rendered() {
+        let names = names();
+
+        for template in [CARGO_TOML, LIB_RS, MAIN_RS, ANSWERS_RS, ANSWERS_TXT] {
+            assert!(!names.render(template).contains("{{"));
+        }
+    }
+
+    #[test]
+    fn test_add_workspace_member() {
+        let contents = "members = [\n    \"aoc\",\n    \"d11-plutonian-pebbles\",\n    \"d14-restroom-redoubt\"\n]\n";
+
+        assert_eq!(
+            add_workspace_member(contents, &names()).unwrap(),
+            "members = [\n    \"aoc\",\n    \"d11-plutonian-pebbles\",\n    \"d12-garden-groups\",\n    \"d14-restroom-redoubt\"\n]\n"
+        );
+
+        let contents = "members = [\n    \"aoc\",\n    \"d11-plutonian-pebbles\"\n]\n";
+
+        assert_eq!(
+            add_workspace_member(contents, &names()).unwrap(),
+            "members = [\n    \"aoc\",\n    \"d11-plutonian-pebbles\",\n    \"d12-garden-groups\"\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_runner_dependency() {
+        let contents = "[features]\nparallel = [\n    \"d14-restroom-redoubt/parallel\",\n]\n\n[dependencies]\nd11-plutonian-pebbles = { path = \"../d11-plutonian-pebbles\" }\nd14-restroom-redoubt = { path = \"../d14-restroom-redoubt\" }\nserde = \"1\"\n";
+
+        assert_eq!(
+            add_runner_dependency(contents, &names()).unwrap(),
+            "[features]\nparallel = [\n    \"d14-restroom-redoubt/parallel\",\n]\n\n[dependencies]\nd11-plutonian-pebbles = { path = \"../d11-plutonian-pebbles\" }\nd12-garden-groups = { path = \"../d12-garden-groups\" }\nd14-restroom-redoubt = { path = \"../d14-restroom-redoubt\" }\nserde = \"1\"\n"
+        );
+    }
+
+    #[test]
+    fn test_add_registration() {
+        let contents = "    let mut registry = Registry::new();\n\n    d2_red_nosed_reports::register(&mut registry);\n    d22_monkey_market::register(&mut registry);\n\n    registry\n";
+
+        assert_eq!(
+            add_registration(contents, &names()).unwrap(),
+            "    let mut registry = Registry::new();\n\n    d2_red_nosed_reports::register(&mut registry);\n    d12_garden_groups::register(&mut registry);\n    d22_monkey_market::register(&mut registry);\n\n    registry\n"
+        );
+    }
+
+    #[test]
+    fn test_add_launch_configs() {
+        let contents = "{\n  \"configurations\": [\n    {\n      \"name\": \"x\"\n    }\n  ]\n}\n";
+        let edited = add_launch_configs(contents, &names()).unwrap();
+
+        assert!(edited.contains("    },\n    {\n      \"type\": \"lldb\""));
+        assert!(edited.contains("\"--bin=d12-garden-groups\""));
+        assert!(edited.ends_with("    }\n  ]\n}\n"));
+        assert_eq!(add_launch_configs(&edited, &names()).unwrap(), edited);
+    }
+}
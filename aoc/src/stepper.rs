@@ -0,0 +1,424 @@
+use std::io::{self, Write};
+
+use aoc24::frames::{FrameCursor, FrameRecorder};
+use aoc24::Result;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Print, PrintStyledContent, Stylize};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+const HELP: &str =
+    ",/. step  </> step 10  g jump  arrows select  hjkl pan  c center  +/- zoom  q quit";
+
+/// Lines below the grid for the status and help.
+const STATUS_LINES: usize = 2;
+
+const MIN_ZOOM: i32 = -4;
+const MAX_ZOOM: i32 = 2;
+
+/// Steps through a recorded simulation in the terminal until the user quits.
+pub fn run(recorder: &FrameRecorder, title: &str) -> Result<()> {
+    let cursor = recorder
+        .cursor()
+        .ok_or("The simulation recorded no frames")?;
+    let mut stepper = Stepper::new(cursor, title);
+    let mut stdout = io::stdout();
+
+    let _terminal = TerminalGuard::enter(&mut stdout)?;
+
+    event_loop(&mut stepper, &mut stdout)
+}
+
+/// Raw mode on the alternate screen, restored when dropped so an error or panic doesn't leave
+/// the terminal unusable.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter(stdout: &mut impl Write) -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        let guard = TerminalGuard;
+        execute!(stdout, EnterAlternateScreen, Hide)?;
+
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn event_loop(stepper: &mut Stepper, stdout: &mut impl Write) -> Result<()> {
+    loop {
+        let (columns, rows) = terminal::size()?;
+        stepper.resize(
+            columns as usize,
+            (rows as usize).saturating_sub(STATUS_LINES),
+        );
+        draw(stepper, stdout)?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !stepper.handle(key) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn draw(stepper: &Stepper, stdout: &mut impl Write) -> Result<()> {
+    queue!(stdout, Clear(ClearType::All))?;
+
+    for (y, line) in stepper.render().iter().enumerate() {
+        queue!(stdout, MoveTo(0, y as u16), Print(line))?;
+    }
+
+    if let Some((x, y, text)) = stepper.selection_on_screen() {
+        queue!(
+            stdout,
+            MoveTo(x as u16, y as u16),
+            PrintStyledContent(text.reverse())
+        )?;
+    }
+
+    stdout.flush()?;
+    Ok(())
+}
+
+/// What the stepper shows, independent of the terminal so it can be driven by plain key events.
+struct Stepper<'a> {
+    cursor: FrameCursor<'a>,
+    title: &'a str,
+    /// Grid cell shown in the top left corner.
+    view: (usize, usize),
+    selected: (usize, usize),
+    /// Positive levels draw a cell several characters wide, negative ones squeeze 2^-level
+    /// cells into every character.
+    zoom: i32,
+    /// Frame number typed after `g`, if a jump is being entered.
+    jump: Option<String>,
+    columns: usize,
+    rows: usize,
+}
+
+impl<'a> Stepper<'a> {
+    fn new(cursor: FrameCursor<'a>, title: &'a str) -> Self {
+        Stepper {
+            cursor,
+            title,
+            view: (0, 0),
+            selected: (0, 0),
+            zoom: 0,
+            jump: None,
+            columns: 80,
+            rows: 24,
+        }
+    }
+
+    fn resize(&mut self, columns: usize, rows: usize) {
+        self.columns = columns.max(1);
+        self.rows = rows.max(1);
+        self.follow_selection();
+    }
+
+    /// Applies a key press, returns `false` once the user quits.
+    fn handle(&mut self, key: KeyEvent) -> bool {
+        if self.jump.is_some() {
+            self.handle_jump(key);
+        } else if !self.handle_command(key) {
+            return false;
+        }
+
+        // Frames can differ in size, keep the selection on the current one
+        let (width, height) = self.grid_size();
+        self.selected = (
+            self.selected.0.min(width - 1),
+            self.selected.1.min(height - 1),
+        );
+
+        true
+    }
+
+    /// Edits the frame number being entered after `g`.
+    fn handle_jump(&mut self, key: KeyEvent) {
+        let Some(jump) = &mut self.jump else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() => jump.push(c),
+            KeyCode::Backspace => {
+                jump.pop();
+            }
+            KeyCode::Enter => {
+                if let Ok(index) = jump.parse() {
+                    self.cursor.seek(index);
+                }
+
+                self.jump = None;
+            }
+            KeyCode::Esc => self.jump = None,
+            _ => {}
+        }
+    }
+
+    fn handle_command(&mut self, key: KeyEvent) -> bool {
+        let (width, height) = self.grid_size();
+        let (x, y) = self.selected;
+        let (pan_x, pan_y) = self.pan_step();
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('.') => self.step(1),
+            KeyCode::Char(',') => self.step(-1),
+            KeyCode::Char('>') => self.step(10),
+            KeyCode::Char('<') => self.step(-10),
+            KeyCode::Home => self.cursor.seek(0),
+            KeyCode::End => self.cursor.seek(usize::MAX),
+            KeyCode::Char('g') => self.jump = Some(String::new()),
+            KeyCode::Left => self.select(x.saturating_sub(1), y),
+            KeyCode::Right => self.select((x + 1).min(width - 1), y),
+            KeyCode::Up => self.select(x, y.saturating_sub(1)),
+            KeyCode::Down => self.select(x, (y + 1).min(height - 1)),
+            KeyCode::Char('h') => self.view.0 = self.view.0.saturating_sub(pan_x),
+            KeyCode::Char('l') => self.view.0 = (self.view.0 + pan_x).min(width - 1),
+            KeyCode::Char('k') => self.view.1 = self.view.1.saturating_sub(pan_y),
+            KeyCode::Char('j') => self.view.1 = (self.view.1 + pan_y).min(height - 1),
+            KeyCode::Char('c') => self.center(),
+            KeyCode::Char('+') | KeyCode::Char('=') => self.set_zoom(self.zoom + 1),
+            KeyCode::Char('-') => self.set_zoom(self.zoom - 1),
+            _ => {}
+        }
+
+        true
+    }
+
+    fn step(&mut self, frames: isize) {
+        let index = self.cursor.index().saturating_add_signed(frames);
+        self.cursor.seek(index);
+    }
+
+    fn select(&mut self, x: usize, y: usize) {
+        self.selected = (x, y);
+        self.follow_selection();
+    }
+
+    fn set_zoom(&mut self, zoom: i32) {
+        self.zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+        self.center();
+    }
+
+    fn center(&mut self) {
+        let (visible_x, visible_y) = self.visible_cells();
+
+        self.view = (
+            self.selected.0.saturating_sub(visible_x / 2),
+            self.selected.1.saturating_sub(visible_y / 2),
+        );
+    }
+
+    /// Pans just far enough to keep the selected cell on screen.
+    fn follow_selection(&mut self) {
+        let (visible_x, visible_y) = self.visible_cells();
+        let (x, y) = self.selected;
+
+        self.view.0 = self.view.0.clamp((x + 1).saturating_sub(visible_x), x);
+        self.view.1 = self.view.1.clamp((y + 1).saturating_sub(visible_y), y);
+    }
+
+    fn grid_size(&self) -> (usize, usize) {
+        (self.cursor.grid().width(), self.cursor.grid().height())
+    }
+
+    fn pan_step(&self) -> (usize, usize) {
+        let (visible_x, visible_y) = self.visible_cells();
+        ((visible_x / 4).max(1), (visible_y / 4).max(1))
+    }
+
+    /// Characters per cell when zoomed in, cells per character when zoomed out.
+    fn cell_width(&self) -> usize {
+        self.zoom.max(0) as usize + 1
+    }
+
+    fn cells_per_char(&self) -> usize {
+        1 << (-self.zoom).max(0)
+    }
+
+    fn visible_cells(&self) -> (usize, usize) {
+        (
+            (self.columns / self.cell_width()).max(1) * self.cells_per_char(),
+            self.rows * self.cells_per_char(),
+        )
+    }
+
+    /// Character standing for the square of cells at `(x, y)`, preferring anything that isn't
+    /// empty floor so robots and walls stay visible when zoomed out.
+    fn block_char(&self, x: usize, y: usize) -> char {
+        let grid = self.cursor.grid();
+        let size = self.cells_per_char();
+
+        let cells = (y..(y + size).min(grid.height()))
+            .flat_map(|cy| (x..(x + size).min(grid.width())).map(move |cx| grid.get(cx, cy)));
+
+        let mut fallback = ' ';
+
+        for c in cells {
+            if c != '.' {
+                return c;
+            }
+
+            fallback = c;
+        }
+
+        fallback
+    }
+
+    fn render(&self) -> Vec<String> {
+        let (width, height) = self.grid_size();
+        let size = self.cells_per_char();
+        let cell_width = self.cell_width();
+
+        let mut lines = (0..self.rows)
+            .map(|row| {
+                let y = self.view.1 + row * size;
+
+                (0..self.columns / cell_width)
+                    .map(|column| self.view.0 + column * size)
+                    .take_while(|&x| x < width && y < height)
+                    .map(|x| format!("{:^cell_width$}", self.block_char(x, y)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        lines.push(self.status());
+        lines.push(match &self.jump {
+            Some(jump) => format!("jump to t = {}_", jump),
+            None => HELP.to_string(),
+        });
+
+        lines
+    }
+
+    fn status(&self) -> String {
+        let (x, y) = self.selected;
+        let cell = self.cursor.grid().get(x, y);
+
+        let change = self
+            .cursor
+            .changes()
+            .iter()
+            .rev()
+            .find(|change| (change.x, change.y) == (x, y))
+            .map(|change| format!(" (was '{}')", change.before))
+            .unwrap_or_default();
+
+        let zoom = match self.zoom {
+            0 => "1:1".to_string(),
+            zoom if zoom > 0 => format!("{}x", zoom + 1),
+            _ => format!("1:{}", self.cells_per_char()),
+        };
+
+        format!(
+            "{}  t = {}/{}  ({}, {}) = '{}'{}  zoom {}",
+            self.title,
+            self.cursor.index(),
+            self.cursor.frame_count() - 1,
+            x,
+            y,
+            cell,
+            change,
+            zoom
+        )
+    }
+
+    /// Screen position and text of the selected cell, if it is on screen.
+    fn selection_on_screen(&self) -> Option<(usize, usize, String)> {
+        let size = self.cells_per_char();
+        let cell_width = self.cell_width();
+
+        let column = self.selected.0.checked_sub(self.view.0)? / size;
+        let row = self.selected.1.checked_sub(self.view.1)? / size;
+
+        if (column + 1) * cell_width > self.columns || row >= self.rows {
+            return None;
+        }
+
+        let block = self.block_char(self.view.0 + column * size, self.view.1 + row * size);
+
+        Some((column * cell_width, row, format!("{:^cell_width$}", block)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc24::grid::Grid;
+    use crossterm::event::KeyModifiers;
+
+    fn press(stepper: &mut Stepper, keys: &str) {
+        for c in keys.chars() {
+            stepper.handle(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+
+    fn recording() -> FrameRecorder {
+        let mut recorder = FrameRecorder::new();
+        let mut grid = "....\n.#..\n....\n...#".parse::<Grid>().unwrap();
+
+        recorder.snapshot(&grid);
+
+        for x in 0..4 {
+            grid.set(x, 0, '>');
+            recorder.snapshot(&grid);
+        }
+
+        recorder
+    }
+
+    #[test]
+    fn test_stepping() {
+        let recorder = recording();
+        let mut stepper = Stepper::new(recorder.cursor().unwrap(), "test");
+
+        press(&mut stepper, "..>");
+        assert_eq!(stepper.cursor.index(), 4);
+        press(&mut stepper, ",");
+        assert_eq!(stepper.render()[0], ">>>.");
+
+        press(&mut stepper, "g1");
+        stepper.handle(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(stepper.cursor.index(), 1);
+        assert!(stepper.status().contains("(0, 0) = '>' (was '.')"));
+    }
+
+    #[test]
+    fn test_jump_to_smaller_frame() {
+        let mut recorder = FrameRecorder::new();
+        recorder.snapshot(&"....\n....\n....\n...#".parse::<Grid>().unwrap());
+        recorder.snapshot(&"..\n.#".parse::<Grid>().unwrap());
+
+        let mut stepper = Stepper::new(recorder.cursor().unwrap(), "test");
+        stepper.select(3, 3);
+
+        press(&mut stepper, "g1");
+        stepper.handle(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert_eq!(stepper.selected, (1, 1));
+        assert!(stepper.status().contains("(1, 1) = '#'"));
+    }
+
+    #[test]
+    fn test_zoom() {
+        let recorder = recording();
+        let mut stepper = Stepper::new(recorder.cursor().unwrap(), "test");
+        stepper.resize(10, 5);
+
+        press(&mut stepper, "-");
+        assert_eq!(stepper.render()[..2], ["#.", ".#"]);
+
+        press(&mut stepper, "++");
+        assert_eq!(stepper.render()[1], ". # . . ");
+    }
+}
@@ -0,0 +1,346 @@
+//! Runs each part in a child process of the runner, so a part that hangs or eats all the memory
+//! is stopped without taking down the whole run. The child is the hidden `worker` subcommand: it
+//! reads the input from stdin and writes its result as a line of JSON to stdout.
+
+use std::env;
+use std::io::{self, Read, Write};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc24::answer::Answer;
+use aoc24::input::InputSpec;
+use aoc24::memory::AllocStats;
+use aoc24::registry::{Entry, Registry};
+use aoc24::solution::Part;
+use aoc24::{log, parallel, Error, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::run::{self, DayResult, Outcome, PartResult};
+
+/// Printed by the standard library when an allocation fails, right before it aborts.
+const ALLOC_FAILURE: &str = "memory allocation of";
+
+/// How often the runner checks whether a worker is done.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Bytes of the worker's stderr kept to tell why it died.
+const STDERR_TAIL: usize = 4096;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Limits {
+    /// Wall-clock time a part may take, parsing included.
+    pub timeout: Option<Duration>,
+    /// Address space of the worker in MiB.
+    pub memory_mib: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_set(&self) -> bool {
+        self.timeout.is_some() || self.memory_mib.is_some()
+    }
+}
+
+/// What a worker reports back about its part.
+#[derive(Serialize, Deserialize)]
+struct Report {
+    parse_ns: u64,
+    parse_error: Option<String>,
+    solve_ns: u64,
+    outcome: WireOutcome,
+    allocs: Option<[u64; 3]>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "status", content = "value", rename_all = "lowercase")]
+enum WireOutcome {
+    Number(String),
+    Str(String),
+    Unsolved,
+    Failed(String),
+    Panicked(String),
+}
+
+/// Entry point of the worker: solves one part of the input read from stdin.
+pub fn serve(registry: &Registry, day: u8, part: Part, spec: &InputSpec) -> Result<()> {
+    let entry = registry
+        .get(day)
+        .ok_or_else(|| format!("Day {} is not registered", day))?;
+
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+
+    let result = run::run_day(entry, spec, &input, &[part]);
+    let part = &result.parts[0];
+
+    let outcome = match &part.outcome {
+        Outcome::Solved(Answer::Unsolved) => WireOutcome::Unsolved,
+        Outcome::Solved(Answer::Str(answer)) => WireOutcome::Str(answer.clone()),
+        Outcome::Solved(answer) => WireOutcome::Number(answer.to_string()),
+        Outcome::Failed(error) => WireOutcome::Failed(error.to_string()),
+        Outcome::Panicked(message) => WireOutcome::Panicked(message.clone()),
+        Outcome::TimedOut(_) | Outcome::OutOfMemory(_) => unreachable!(),
+    };
+
+    let report = Report {
+        parse_ns: result.parse_elapsed.as_nanos() as u64,
+        parse_error: result.parse_error.as_ref().map(Error::to_string),
+        solve_ns: part.elapsed.as_nanos() as u64,
+        outcome,
+        allocs: part
+            .allocs
+            .map(|allocs| [allocs.allocations, allocs.bytes, allocs.peak_bytes]),
+    };
+
+    let json = serde_json::to_string(&report).map_err(|e| e.to_string())?;
+    writeln!(io::stdout(), "{}", json)?;
+
+    Ok(())
+}
+
+/// Same as [`run::run_day`], but with every part in its own worker under `limits`.
+pub fn run_day(
+    entry: &Entry,
+    spec: &InputSpec,
+    input: &str,
+    parts: &[Part],
+    limits: &Limits,
+) -> Result<DayResult> {
+    let mut result = DayResult {
+        day: entry.day(),
+        title: entry.title(),
+        input_sha256: format!("{:x}", Sha256::digest(input)),
+        parse_elapsed: Duration::ZERO,
+        parse_error: None,
+        parts: Vec::new(),
+    };
+
+    for &part in parts {
+        let start = Instant::now();
+        let (status, stdout, stderr) = run_worker(entry.day(), part, spec, input, limits)?;
+        let elapsed = start.elapsed();
+
+        let outcome = match status {
+            None => Outcome::TimedOut(elapsed),
+            Some(_) if stderr.contains(ALLOC_FAILURE) => {
+                Outcome::OutOfMemory(limits.memory_mib.unwrap_or_default())
+            }
+            Some(status) => match stdout.lines().last().map(serde_json::from_str::<Report>) {
+                Some(Ok(report)) => {
+                    result.parse_elapsed = Duration::from_nanos(report.parse_ns);
+                    result.parse_error = report.parse_error.map(Error::new);
+
+                    result.parts.push(PartResult {
+                        part,
+                        outcome: outcome(report.outcome),
+                        elapsed: Duration::from_nanos(report.solve_ns),
+                        allocs: report
+                            .allocs
+                            .map(|[allocations, bytes, peak_bytes]| AllocStats {
+                                allocations,
+                                bytes,
+                                peak_bytes,
+                            }),
+                        ledger: None,
+                    });
+
+                    continue;
+                }
+                _ => Outcome::Panicked(crash_message(status, &stderr)),
+            },
+        };
+
+        result.parts.push(PartResult {
+            part,
+            outcome,
+            elapsed,
+            allocs: None,
+            ledger: None,
+        });
+    }
+
+    Ok(result)
+}
+
+fn outcome(outcome: WireOutcome) -> Outcome {
+    match outcome {
+        WireOutcome::Number(answer) => Answer::number(&answer)
+            .map(Outcome::Solved)
+            .unwrap_or_else(|| Outcome::Failed(Error::new(format!("Invalid answer {}", answer)))),
+        WireOutcome::Str(answer) => Outcome::Solved(Answer::Str(answer)),
+        WireOutcome::Unsolved => Outcome::Solved(Answer::Unsolved),
+        WireOutcome::Failed(error) => Outcome::Failed(Error::new(error)),
+        WireOutcome::Panicked(message) => Outcome::Panicked(message),
+    }
+}
+
+fn crash_message(status: ExitStatus, stderr: &str) -> String {
+    match stderr.lines().rev().find(|line| !line.trim().is_empty()) {
+        Some(line) => format!("worker {}: {}", status, line.trim()),
+        None => format!("worker {}", status),
+    }
+}
+
+/// Runs a worker to completion, or kills it once it runs out of time. Returns the exit status,
+/// `None` on timeout, and the output of the worker.
+fn run_worker(
+    day: u8,
+    part: Part,
+    spec: &InputSpec,
+    input: &str,
+    limits: &Limits,
+) -> Result<(Option<ExitStatus>, String, String)> {
+    let mut command = Command::new(env::current_exe()?);
+    command
+        .args(["worker", "--day", &day.to_string()])
+        .args(["--part", &part.to_string()])
+        .args(["--input", &spec.to_string()])
+        .env(log::LEVEL_VAR, log::level().to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if parallel::ENABLED {
+        command.args(["--threads", &parallel::threads().to_string()]);
+    }
+
+    if let Some(memory_mib) = limits.memory_mib {
+        limit_memory(&mut command, memory_mib)?;
+    }
+
+    let mut child = command.spawn()?;
+
+    // The worker reads all of its input before it starts, so this can't block on a full pipe. A
+    // worker that died right away closes it early, which its exit status explains better.
+    match child.stdin.take().unwrap().write_all(input.as_bytes()) {
+        Err(error) if error.kind() != io::ErrorKind::BrokenPipe => return Err(error.into()),
+        _ => {}
+    }
+
+    let stdout = child.stdout.take().unwrap();
+    let stdout = thread::spawn(move || {
+        let mut stdout = stdout;
+        let mut output = String::new();
+        let _ = stdout.read_to_string(&mut output);
+        output
+    });
+
+    let stderr = child.stderr.take().unwrap();
+    let stderr = thread::spawn(move || forward_stderr(stderr, io::stderr()));
+
+    let status = wait(&mut child, limits.timeout)?;
+
+    Ok((
+        status,
+        stdout.join().unwrap_or_default(),
+        stderr.join().unwrap_or_default(),
+    ))
+}
+
+fn wait(child: &mut Child, timeout: Option<Duration>) -> Result<Option<ExitStatus>> {
+    let Some(timeout) = timeout else {
+        return Ok(Some(child.wait()?));
+    };
+
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Passes the worker's logs through and keeps the end of them.
+fn forward_stderr(mut stderr: impl Read, mut to: impl Write) -> String {
+    let mut tail = Vec::new();
+    let mut buffer = [0; 1024];
+
+    while let Ok(n @ 1..) = stderr.read(&mut buffer) {
+        let _ = to.write_all(&buffer[..n]);
+
+        tail.extend_from_slice(&buffer[..n]);
+        tail.drain(..tail.len().saturating_sub(STDERR_TAIL));
+    }
+
+    String::from_utf8_lossy(&tail).into_owned()
+}
+
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, memory_mib: u64) -> Result<()> {
+    use std::os::unix::process::CommandExt;
+
+    let bytes = memory_mib.saturating_mul(1024 * 1024) as libc::rlim_t;
+    let limit = libc::rlimit {
+        rlim_cur: bytes,
+        rlim_max: bytes,
+    };
+
+    // Safety: setrlimit is async-signal-safe, and nothing else runs between fork and exec
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+                Ok(())
+            } else {
+                Err(io::Error::last_os_error())
+            }
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_command: &mut Command, _memory_mib: u64) -> Result<()> {
+    Err("--memory is only supported on Unix".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_round_trip() {
+        let json = serde_json::to_string(&Report {
+            parse_ns: 10,
+            parse_error: None,
+            solve_ns: 20,
+            outcome: WireOutcome::Number("340282366920938463463374607431768211456".to_string()),
+            allocs: Some([1, 2, 3]),
+        })
+        .unwrap();
+
+        let report = serde_json::from_str::<Report>(&json).unwrap();
+
+        assert!(matches!(
+            outcome(report.outcome),
+            Outcome::Solved(answer) if answer.matches("340282366920938463463374607431768211456")
+        ));
+        assert!(matches!(
+            outcome(WireOutcome::Str("6,0,1".to_string())),
+            Outcome::Solved(Answer::Str(answer)) if answer == "6,0,1"
+        ));
+        assert!(matches!(
+            outcome(WireOutcome::Number("twelve".to_string())),
+            Outcome::Failed(_)
+        ));
+    }
+
+    #[test]
+    fn test_forward_stderr() {
+        let log = "x".repeat(STDERR_TAIL) + "\nmemory allocation of 1024 bytes failed\n";
+        let tail = forward_stderr(log.as_bytes(), io::sink());
+
+        assert_eq!(tail.len(), STDERR_TAIL);
+        assert!(tail.contains(ALLOC_FAILURE));
+    }
+}
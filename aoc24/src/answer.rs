@@ -0,0 +1,217 @@
+use std::fmt::{self, Display, Formatter};
+
+use num_bigint::BigInt;
+use num_traits::{CheckedAdd, CheckedMul, One, Zero};
+
+use crate::{Error, Result};
+
+/// Answer of a part. Numeric answers compare by value, so `Int(5)`, `UInt(5)` and `Big(5)` are
+/// equal and all match `5` in the manifest.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    I128(i128),
+    U128(u128),
+    Big(BigInt),
+    Str(String),
+    Unsolved,
+}
+
+impl Answer {
+    /// Numeric value of the answer, if it has one. Used to compare answers against bounds.
+    pub fn to_bigint(&self) -> Option<BigInt> {
+        match self {
+            Answer::Int(value) => Some(BigInt::from(*value)),
+            Answer::UInt(value) => Some(BigInt::from(*value)),
+            Answer::I128(value) => Some(BigInt::from(*value)),
+            Answer::U128(value) => Some(BigInt::from(*value)),
+            Answer::Big(value) => Some(value.clone()),
+            Answer::Str(value) => value.parse().ok(),
+            Answer::Unsolved => None,
+        }
+    }
+
+    /// Numeric answer written as `value`, in the smallest variant that holds it.
+    pub fn number(value: &str) -> Option<Answer> {
+        let value = value.trim();
+
+        value
+            .parse::<i64>()
+            .map(Answer::Int)
+            .or_else(|_| value.parse::<u64>().map(Answer::UInt))
+            .or_else(|_| value.parse::<i128>().map(Answer::I128))
+            .or_else(|_| value.parse::<u128>().map(Answer::U128))
+            .or_else(|_| value.parse::<BigInt>().map(Answer::Big))
+            .ok()
+    }
+
+    /// Whether the answer is the one written down as `expected`, e.g. in the manifest or ledger.
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim();
+
+        match (self, expected.parse::<BigInt>()) {
+            (Answer::Str(value), _) => value == expected,
+            (Answer::Unsolved, _) => expected == "unsolved",
+            (_, Ok(expected)) => self.to_bigint() == Some(expected),
+            (_, Err(_)) => false,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Str(a), Answer::Str(b)) => a == b,
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            (Answer::Str(_) | Answer::Unsolved, _) | (_, Answer::Str(_) | Answer::Unsolved) => {
+                false
+            }
+            _ => self.to_bigint() == other.to_bigint(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::UInt(value) => write!(f, "{}", value),
+            Answer::I128(value) => write!(f, "{}", value),
+            Answer::U128(value) => write!(f, "{}", value),
+            Answer::Big(value) => write!(f, "{}", value),
+            Answer::Str(value) => write!(f, "{}", value),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Int(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::I128(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::UInt(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::UInt(value)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::U128(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::UInt(value as u64)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+/// Sums the values, failing instead of wrapping around when the sum doesn't fit in `T`.
+pub fn checked_sum<T>(values: impl IntoIterator<Item = T>) -> Result<T>
+where
+    T: CheckedAdd + Zero + Display,
+{
+    values.into_iter().try_fold(T::zero(), |sum, value| {
+        sum.checked_add(&value)
+            .ok_or_else(|| Error::new(format!("overflow adding {} to {}", value, sum)))
+    })
+}
+
+/// Multiplies the values, failing instead of wrapping around when the product doesn't fit in `T`.
+pub fn checked_product<T>(values: impl IntoIterator<Item = T>) -> Result<T>
+where
+    T: CheckedMul + One + Display,
+{
+    values.into_iter().try_fold(T::one(), |product, value| {
+        product
+            .checked_mul(&value)
+            .ok_or_else(|| Error::new(format!("overflow multiplying {} by {}", product, value)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equality() {
+        let big = BigInt::from(u128::MAX) * BigInt::from(3);
+
+        assert_eq!(Answer::from(5), Answer::from(5u64));
+        assert_eq!(Answer::from(5u128), Answer::from(BigInt::from(5)));
+        assert_ne!(Answer::from(5), Answer::from("5"));
+        assert!(Answer::from(-7i128).matches("-7"));
+        assert!(Answer::from(big.clone()).matches(&big.to_string()));
+        assert!(Answer::from("LIME").matches("LIME"));
+        assert!(!Answer::from(5).matches("five"));
+        assert!(Answer::Unsolved.matches("unsolved"));
+    }
+
+    #[test]
+    fn test_number() {
+        assert!(matches!(Answer::number("-5"), Some(Answer::Int(-5))));
+        assert!(matches!(
+            Answer::number(&u64::MAX.to_string()),
+            Some(Answer::UInt(_))
+        ));
+        assert!(matches!(
+            Answer::number(&u128::MAX.to_string()),
+            Some(Answer::U128(_))
+        ));
+        assert!(matches!(
+            Answer::number(&format!("{}0", u128::MAX)),
+            Some(Answer::Big(_))
+        ));
+        assert!(Answer::number("6,0,1").is_none());
+    }
+
+    #[test]
+    fn test_checked_accumulation() {
+        assert_eq!(checked_sum([1i32, 2, 3]).unwrap(), 6);
+        assert!(checked_sum([i32::MAX, 1]).is_err());
+        assert_eq!(checked_product([2u64, 3, 4]).unwrap(), 24);
+        assert!(checked_product([u64::MAX, 2]).is_err());
+    }
+}
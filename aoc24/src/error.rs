@@ -0,0 +1,132 @@
+use std::fmt::{self, Debug, Display, Formatter};
+use std::io;
+
+use crate::parse::ParseError;
+
+/// Error of a day or of the tooling around it. Besides the message it carries whatever is known
+/// about where things went wrong, which is filled in as the error travels up:
+///
+/// ```text
+/// day 1, input.txt:2:3: invalid value `x`: invalid digit found in string
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Error {
+    message: String,
+    day: Option<u8>,
+    file: Option<String>,
+    position: Option<(usize, usize)>,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Error {
+            message: message.into(),
+            day: None,
+            file: None,
+            position: None,
+        }
+    }
+
+    /// Sets the day, unless an inner error already did.
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    /// Sets the input file, unless an inner error already did.
+    pub fn with_file(mut self, file: impl Display) -> Self {
+        self.file.get_or_insert_with(|| file.to_string());
+        self
+    }
+
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.position = Some((line, column));
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// 1-based line and column in the input.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        self.position
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+
+        match (&self.file, self.position) {
+            (Some(file), Some((line, column))) => write!(f, "{}:{}:{}: ", file, line, column)?,
+            (Some(file), None) => write!(f, "{}: ", file)?,
+            (None, Some((line, column))) => write!(f, "line {}, column {}: ", line, column)?,
+            (None, None) => {}
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+// `main` prints a returned error with `Debug`, which should be the same readable line
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::new(error.message()).at(error.line(), error.column())
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::new(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::new(message)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::new(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = Error::from(ParseError::new(2, 3, "invalid value `x`"))
+            .with_file("input.txt")
+            .with_day(1)
+            .with_day(7);
+
+        assert_eq!(error.to_string(), "day 1, input.txt:2:3: invalid value `x`");
+        assert_eq!(format!("{:?}", error), error.to_string());
+        assert_eq!(Error::new("no guard").to_string(), "no guard");
+    }
+}
@@ -0,0 +1,404 @@
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+
+/// A cell that changed between two frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub x: usize,
+    pub y: usize,
+    pub before: char,
+    pub after: char,
+}
+
+enum Frame {
+    Full(Grid),
+    Diff(Vec<Change>),
+}
+
+/// Collects the states of a step-by-step simulation so they can be watched afterwards.
+///
+/// Simulations either push whole `Grid` snapshots, of which only the changed cells are kept, or
+/// push the changed cells themselves when building a grid for every step would be too slow.
+#[derive(Default)]
+pub struct FrameRecorder {
+    frames: Vec<Frame>,
+    current: Option<Grid>,
+}
+
+impl FrameRecorder {
+    pub fn new() -> Self {
+        FrameRecorder::default()
+    }
+
+    /// Records `grid` as the next frame.
+    pub fn snapshot(&mut self, grid: &Grid) {
+        let current = match &mut self.current {
+            Some(current)
+                if current.width() == grid.width() && current.height() == grid.height() =>
+            {
+                current
+            }
+            _ => {
+                self.current = Some(grid.clone());
+                self.frames.push(Frame::Full(grid.clone()));
+                return;
+            }
+        };
+
+        let changes = grid
+            .coordinates_iter()
+            .filter(|&(x, y)| current.get(x, y) != grid.get(x, y))
+            .map(|(x, y)| Change {
+                x,
+                y,
+                before: current.get(x, y),
+                after: grid.get(x, y),
+            })
+            .collect::<Vec<_>>();
+
+        for change in &changes {
+            current.set(change.x, change.y, change.after);
+        }
+
+        self.frames.push(Frame::Diff(changes));
+    }
+
+    /// Records the next frame as the previous one with the given cells set.
+    ///
+    /// Panics if nothing was recorded yet, there needs to be a snapshot to apply the cells to.
+    pub fn diff(&mut self, cells: impl IntoIterator<Item = (usize, usize, char)>) {
+        let current = self
+            .current
+            .as_mut()
+            .expect("a diff needs a snapshot to apply to");
+
+        let changes = cells
+            .into_iter()
+            .map(|(x, y, after)| {
+                let before = current.get(x, y);
+                current.set(x, y, after);
+
+                Change {
+                    x,
+                    y,
+                    before,
+                    after,
+                }
+            })
+            .collect();
+
+        self.frames.push(Frame::Diff(changes));
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The recorded frames in order, each rebuilt as a whole grid.
+    pub fn frames(&self) -> impl Iterator<Item = Grid> + '_ {
+        self.frames.iter().scan(None::<Grid>, |grid, frame| {
+            match frame {
+                Frame::Full(full) => *grid = Some(full.clone()),
+                Frame::Diff(changes) => {
+                    let grid = grid.as_mut()?;
+
+                    for change in changes {
+                        grid.set(change.x, change.y, change.after);
+                    }
+                }
+            }
+
+            grid.clone()
+        })
+    }
+
+    /// Cursor on the first frame, or `None` when nothing was recorded.
+    pub fn cursor(&self) -> Option<FrameCursor<'_>> {
+        let Frame::Full(grid) = self.frames.first()? else {
+            unreachable!("recordings start with a snapshot");
+        };
+
+        Some(FrameCursor {
+            frames: &self.frames,
+            index: 0,
+            grid: grid.clone(),
+        })
+    }
+
+    /// Plays the frames back on stdout, redrawing the screen `fps` times a second.
+    pub fn play(&self, fps: f64) -> Result<()> {
+        if !(fps > 0.0 && fps.is_finite()) {
+            return Err(Error::new(format!("Invalid frame rate: {}", fps)));
+        }
+
+        let delay = Duration::from_secs_f64(1.0 / fps);
+        let mut stdout = io::stdout().lock();
+
+        for (i, grid) in self.frames().enumerate() {
+            write!(stdout, "\x1b[H\x1b[2J{}", grid.to_text())?;
+            writeln!(stdout, "frame {}/{}", i + 1, self.len())?;
+            stdout.flush()?;
+
+            thread::sleep(delay);
+        }
+
+        Ok(())
+    }
+
+    /// Writes every frame to its own file in `dir`, named `frame_000000.<ext>`.
+    pub fn write(&self, dir: &Path, format: FrameFormat) -> Result<()> {
+        fs::create_dir_all(dir).map_err(|e| Error::from(e).with_file(dir.display()))?;
+
+        for (i, grid) in self.frames().enumerate() {
+            let path = dir.join(format!("frame_{:06}.{}", i, format.extension()));
+
+            let contents = match format {
+                FrameFormat::Text => grid.to_text().into_bytes(),
+                FrameFormat::Ppm => to_ppm(&grid),
+            };
+
+            fs::write(&path, contents).map_err(|e| Error::from(e).with_file(path.display()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Position in a recording that moves in both directions, applying or undoing one frame per
+/// step instead of replaying everything from the start.
+pub struct FrameCursor<'a> {
+    frames: &'a [Frame],
+    index: usize,
+    grid: Grid,
+}
+
+impl<'a> FrameCursor<'a> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// Cells that changed on the way into the current frame, empty for a full snapshot.
+    pub fn changes(&self) -> &'a [Change] {
+        match &self.frames[self.index] {
+            Frame::Full(_) => &[],
+            Frame::Diff(changes) => changes,
+        }
+    }
+
+    pub fn forward(&mut self) -> bool {
+        let Some(frame) = self.frames.get(self.index + 1) else {
+            return false;
+        };
+
+        match frame {
+            Frame::Full(grid) => self.grid = grid.clone(),
+            Frame::Diff(changes) => {
+                for change in changes {
+                    self.grid.set(change.x, change.y, change.after);
+                }
+            }
+        }
+
+        self.index += 1;
+        true
+    }
+
+    pub fn back(&mut self) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+
+        match &self.frames[self.index] {
+            Frame::Diff(changes) => {
+                for change in changes.iter().rev() {
+                    self.grid.set(change.x, change.y, change.before);
+                }
+
+                self.index -= 1;
+            }
+            Frame::Full(_) => self.rebuild(self.index - 1),
+        }
+
+        true
+    }
+
+    /// Moves to the frame at `index`, or the last frame if there are fewer.
+    pub fn seek(&mut self, index: usize) {
+        let index = index.min(self.frames.len() - 1);
+
+        while self.index < index {
+            self.forward();
+        }
+
+        while self.index > index {
+            self.back();
+        }
+    }
+
+    /// Replays up to `index` from the closest snapshot before it.
+    fn rebuild(&mut self, index: usize) {
+        let start = (0..=index)
+            .rev()
+            .find(|&i| matches!(self.frames[i], Frame::Full(_)))
+            .unwrap_or(0);
+
+        if let Frame::Full(grid) = &self.frames[start] {
+            self.grid = grid.clone();
+        }
+
+        self.index = start;
+        self.seek(index);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameFormat {
+    Text,
+    Ppm,
+}
+
+impl FrameFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            FrameFormat::Text => "txt",
+            FrameFormat::Ppm => "ppm",
+        }
+    }
+}
+
+impl Display for FrameFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            FrameFormat::Text => "text",
+            FrameFormat::Ppm => "ppm",
+        })
+    }
+}
+
+impl FromStr for FrameFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim() {
+            "text" | "txt" => Ok(FrameFormat::Text),
+            "ppm" => Ok(FrameFormat::Ppm),
+            _ => Err(format!(
+                "Invalid frame format: {} (expected text or ppm)",
+                s
+            )),
+        }
+    }
+}
+
+/// Binary PPM image with every cell drawn as a `CELL_SIZE` pixels wide square.
+fn to_ppm(grid: &Grid) -> Vec<u8> {
+    const CELL_SIZE: usize = 4;
+
+    let width = grid.width() * CELL_SIZE;
+    let height = grid.height() * CELL_SIZE;
+
+    let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    image.reserve(width * height * 3);
+
+    for py in 0..height {
+        for px in 0..width {
+            image.extend(color(grid.get(px / CELL_SIZE, py / CELL_SIZE)));
+        }
+    }
+
+    image
+}
+
+fn color(c: char) -> [u8; 3] {
+    match c {
+        '.' | ' ' => [0, 0, 0],
+        '#' => [200, 200, 200],
+        '0'..='9' => [0, 80 + 17 * (c as u8 - b'0'), 0],
+        _ => {
+            // Any other character gets a bright color of its own
+            let hash = (c as u32).wrapping_mul(2654435761);
+            [
+                (hash >> 24) as u8 | 64,
+                (hash >> 16) as u8 | 64,
+                (hash >> 8) as u8 | 64,
+            ]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay() {
+        let mut recorder = FrameRecorder::new();
+        let mut grid = "#.\n..".parse::<Grid>().unwrap();
+
+        recorder.snapshot(&grid);
+        grid.set(1, 1, '^');
+        recorder.snapshot(&grid);
+        recorder.diff([(1, 1, '.'), (0, 1, '<')]);
+
+        let frames = recorder
+            .frames()
+            .map(|grid| grid.to_text())
+            .collect::<Vec<_>>();
+
+        assert_eq!(frames, ["#.\n..\n", "#.\n.^\n", "#.\n<.\n"]);
+    }
+
+    #[test]
+    fn test_cursor() {
+        let mut recorder = FrameRecorder::new();
+        let mut grid = "..\n..".parse::<Grid>().unwrap();
+
+        recorder.snapshot(&grid);
+        recorder.diff([(0, 0, '1'), (0, 0, '2')]);
+        recorder.snapshot(&"abc".parse::<Grid>().unwrap());
+        grid.set(1, 1, '#');
+        recorder.snapshot(&grid);
+
+        let mut cursor = recorder.cursor().unwrap();
+
+        cursor.seek(3);
+        assert_eq!(cursor.grid().to_text(), "..\n.#\n");
+        assert!(cursor.back());
+        assert_eq!(cursor.grid().to_text(), "abc\n");
+        assert!(cursor.back());
+        assert_eq!(cursor.grid().to_text(), "2.\n..\n");
+        assert_eq!(cursor.changes().len(), 2);
+        assert!(cursor.back());
+        assert_eq!(cursor.grid().to_text(), "..\n..\n");
+        assert!(!cursor.back());
+    }
+
+    #[test]
+    fn test_ppm() {
+        let grid = "#.".parse::<Grid>().unwrap();
+        let image = to_ppm(&grid);
+
+        assert!(image.starts_with(b"P6\n8 4\n255\n"));
+        assert_eq!(image.len(), "P6\n8 4\n255\n".len() + 8 * 4 * 3);
+    }
+}
@@ -1,6 +1,10 @@
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use std::{cmp, fmt};
 
+use crate::parse::{ParseError, Span};
+
+#[derive(Debug, PartialEq, Eq)]
 pub struct Grid {
     width: usize,
     height: usize,
@@ -46,6 +50,19 @@ impl Grid {
         self.data[y][x] = value;
     }
 
+    /// One character per cell and a line per row, the format the grid was parsed from. Unlike
+    /// `Display` it doesn't pad cells to line up multi-digit values.
+    pub fn to_text(&self) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+
+        for row in &self.data {
+            text.extend(row);
+            text.push('\n');
+        }
+
+        text
+    }
+
     pub fn coordinates_iter(&self) -> impl Iterator<Item = (usize, usize)> + use<'_> {
         (0..self.height()).flat_map(move |y| (0..self.width()).map(move |x| (x, y)))
     }
@@ -125,18 +142,77 @@ impl Display for Grid {
     }
 }
 
-impl From<&str> for Grid {
-    fn from(input: &str) -> Self {
-        let width = input.lines().next().unwrap().len();
-        let height = input.lines().count();
-        let mut grid = Grid::new(width, height);
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    /// Parses one row per line. Every row needs to be as wide as the first one.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let span = Span::new(input);
+        let lines = span.lines();
+
+        let width = match lines.first() {
+            Some(line) if !line.is_empty() => line.as_str().chars().count(),
+            _ => return Err(span.error("expected at least one row")),
+        };
+
+        let mut grid = Grid::new(width, lines.len());
+
+        for (y, line) in lines.iter().enumerate() {
+            let row = line.as_str().chars().collect::<Vec<char>>();
+
+            if row.len() != width {
+                return Err(line.error(format!(
+                    "expected a row of {} cells, found {}",
+                    width,
+                    row.len()
+                )));
+            }
+
+            grid.data[y] = row;
+        }
+
+        Ok(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+    use proptest::prelude::*;
+
+    fn rows() -> impl Strategy<Value = Vec<String>> {
+        (1..20usize, 1..20usize).prop_flat_map(|(width, height)| {
+            let row = prop::string::string_regex(&format!("[.#a-z0-9^<>]{{{}}}", width)).unwrap();
+            prop::collection::vec(row, height)
+        })
+    }
 
-        for (y, line) in input.lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                grid.data[y][x] = c;
+    proptest! {
+        #[test]
+        fn test_parse(rows in rows()) {
+            let grid = rows.join("\n").parse::<Grid>().unwrap();
+
+            prop_assert_eq!(grid.width(), rows[0].len());
+            prop_assert_eq!(grid.height(), rows.len());
+
+            for (x, y) in grid.coordinates_iter() {
+                prop_assert_eq!(grid.get(x, y), rows[y].as_bytes()[x] as char);
             }
         }
 
-        grid
+        #[test]
+        fn test_round_trip(rows in rows()) {
+            let grid = rows.join("\n").parse::<Grid>().unwrap();
+            let text = grid.to_text();
+
+            prop_assert_eq!(&text, &(rows.join("\n") + "\n"));
+            prop_assert_eq!(text.parse::<Grid>().unwrap(), grid);
+        }
+
+        #[test]
+        fn test_no_panic(input in testing::inputs("[.#\n\r ab]{0,40}")) {
+            let _ = input.parse::<Grid>();
+        }
     }
 }
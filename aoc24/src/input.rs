@@ -0,0 +1,261 @@
+use std::env;
+use std::ffi::OsString;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// Overrides where named inputs are looked up. Inputs are expected under a directory named after
+/// the day's crate, e.g. `$AOC_INPUT_DIR/d6-guard-gallivant/input.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory under the user's data directory that holds the real inputs, which aren't ours to
+/// publish and so are kept out of the repository.
+const DATA_DIR_NAME: &str = "aoc24";
+
+pub const REAL_FILE_NAME: &str = "input.txt";
+
+const EXAMPLE_PREFIX: &str = "input_example_";
+const EXAMPLE_SUFFIX: &str = ".txt";
+
+pub fn example_file_name(variant: u32) -> String {
+    format!("{}{}{}", EXAMPLE_PREFIX, variant, EXAMPLE_SUFFIX)
+}
+
+/// Per-user input directory, `$XDG_DATA_HOME/aoc24` or `~/.local/share/aoc24`.
+pub fn data_dir() -> Option<PathBuf> {
+    data_dir_from(env::var_os("XDG_DATA_HOME"), env::var_os("HOME"))
+}
+
+fn data_dir_from(xdg_data_home: Option<OsString>, home: Option<OsString>) -> Option<PathBuf> {
+    // The XDG spec says relative paths are invalid and should be ignored
+    let base = xdg_data_home
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| home.map(|home| Path::new(&home).join(".local/share")))?;
+
+    Some(base.join(DATA_DIR_NAME))
+}
+
+fn parse_example_file_name(file_name: &str) -> Option<u32> {
+    file_name
+        .strip_prefix(EXAMPLE_PREFIX)?
+        .strip_suffix(EXAMPLE_SUFFIX)?
+        .parse()
+        .ok()
+}
+
+/// Which input to run a day against.
+///
+/// Parses from `-` (stdin), `input` (the real input), `example` or `example:N`, and treats anything
+/// else as a path.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSpec {
+    #[default]
+    Real,
+    Example(u32),
+    Stdin,
+    Path(PathBuf),
+}
+
+impl InputSpec {
+    pub fn file_name(&self) -> Option<String> {
+        match self {
+            InputSpec::Real => Some(REAL_FILE_NAME.to_string()),
+            InputSpec::Example(variant) => Some(example_file_name(*variant)),
+            InputSpec::Stdin | InputSpec::Path(_) => None,
+        }
+    }
+
+    /// Name of the input in diagnostics.
+    pub fn source_name(&self) -> String {
+        match self {
+            InputSpec::Stdin => "<stdin>".to_string(),
+            InputSpec::Path(path) => path.display().to_string(),
+            _ => self.file_name().unwrap(),
+        }
+    }
+}
+
+impl FromStr for InputSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s == "-" {
+            return Ok(InputSpec::Stdin);
+        }
+
+        if s == "input" || s == "real" {
+            return Ok(InputSpec::Real);
+        }
+
+        if s == "example" {
+            return Ok(InputSpec::Example(1));
+        }
+
+        if let Some(variant) = s.strip_prefix("example:") {
+            return match variant.parse::<u32>() {
+                Ok(variant) if variant > 0 => Ok(InputSpec::Example(variant)),
+                _ => Err(format!("Invalid example variant: {}", variant)),
+            };
+        }
+
+        Ok(InputSpec::Path(PathBuf::from(s)))
+    }
+}
+
+impl Display for InputSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSpec::Real => write!(f, "input"),
+            InputSpec::Example(variant) => write!(f, "example:{}", variant),
+            InputSpec::Stdin => write!(f, "-"),
+            InputSpec::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Finds the inputs of a single day, relative to the day's crate directory.
+pub struct InputResolver {
+    crate_dir: PathBuf,
+}
+
+impl InputResolver {
+    pub fn new(crate_dir: impl Into<PathBuf>) -> Self {
+        InputResolver {
+            crate_dir: crate_dir.into(),
+        }
+    }
+
+    pub fn crate_dir(&self) -> &Path {
+        &self.crate_dir
+    }
+
+    /// Directories searched for named inputs, in order of precedence. Examples are found in the
+    /// crate directory, real inputs in one of the user's directories before it.
+    pub fn search_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
+
+        if let Some(crate_name) = self.crate_dir.file_name() {
+            let user_dirs = env::var_os(INPUT_DIR_VAR)
+                .map(PathBuf::from)
+                .into_iter()
+                .chain(data_dir());
+
+            dirs.extend(user_dirs.map(|dir| dir.join(crate_name)));
+        }
+
+        dirs.push(self.crate_dir.clone());
+        dirs
+    }
+
+    /// Where the real input should be saved, in the first directory searched.
+    pub fn real_input_path(&self) -> PathBuf {
+        self.search_dirs()
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| self.crate_dir.clone())
+            .join(REAL_FILE_NAME)
+    }
+
+    /// Resolves `spec` to a file, or `None` for stdin.
+    pub fn resolve(&self, spec: &InputSpec) -> Result<Option<PathBuf>> {
+        let file_name = match spec {
+            InputSpec::Stdin => return Ok(None),
+            InputSpec::Path(path) => return Ok(Some(path.clone())),
+            _ => spec.file_name().unwrap(),
+        };
+
+        let candidates = self
+            .search_dirs()
+            .into_iter()
+            .map(|dir| dir.join(&file_name))
+            .collect::<Vec<_>>();
+
+        candidates
+            .iter()
+            .find(|candidate| candidate.is_file())
+            .cloned()
+            .map(Some)
+            .ok_or_else(|| {
+                if *spec == InputSpec::Real {
+                    return Error::new(format!(
+                        "No puzzle input, save yours to {}",
+                        self.real_input_path().display()
+                    ));
+                }
+
+                let searched = candidates
+                    .iter()
+                    .map(|candidate| candidate.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                Error::new(format!("Input {} not found (searched {})", spec, searched))
+            })
+    }
+
+    pub fn read(&self, spec: &InputSpec) -> Result<String> {
+        match self.resolve(spec)? {
+            Some(path) => read_file(&path),
+            None => read_stdin(),
+        }
+    }
+
+    /// Example variants available for this day, in ascending order.
+    pub fn examples(&self) -> Vec<u32> {
+        let mut variants = self
+            .search_dirs()
+            .iter()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| parse_example_file_name(&entry.file_name().to_string_lossy()))
+            .collect::<Vec<_>>();
+
+        variants.sort();
+        variants.dedup();
+        variants
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| Error::from(e).with_file(path.display()))
+}
+
+fn read_stdin() -> Result<String> {
+    let mut contents = String::new();
+
+    io::stdin()
+        .read_to_string(&mut contents)
+        .map_err(|e| Error::from(e).with_file(InputSpec::Stdin.source_name()))?;
+
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_dir() {
+        let home = Some(OsString::from("/home/elf"));
+
+        assert_eq!(
+            data_dir_from(Some("/data".into()), home.clone()),
+            Some(PathBuf::from("/data/aoc24"))
+        );
+        assert_eq!(
+            data_dir_from(Some("relative".into()), home.clone()),
+            Some(PathBuf::from("/home/elf/.local/share/aoc24"))
+        );
+        assert_eq!(
+            data_dir_from(None, home),
+            Some(PathBuf::from("/home/elf/.local/share/aoc24"))
+        );
+        assert_eq!(data_dir_from(None, None), None);
+    }
+}
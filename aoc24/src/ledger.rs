@@ -0,0 +1,422 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::parse::Span;
+use crate::solution::Part;
+
+/// Submissions of a day's real answers, kept next to the day's crate:
+///
+/// ```text
+/// # part  verdict    answer
+/// 2       too-low    123
+/// 2       confirmed  7380
+/// ```
+pub const LEDGER_FILE_NAME: &str = "ledger.txt";
+
+/// Answers of the last run on the real input, to tell when they change between runs. Unlike the
+/// ledger this changes on every run, so it isn't tracked.
+pub const LAST_ANSWERS_FILE_NAME: &str = "last-answers.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Confirmed,
+    TooLow,
+    TooHigh,
+    Wrong,
+}
+
+impl Verdict {
+    const CONFIRMED: &'static str = "confirmed";
+    const TOO_LOW: &'static str = "too-low";
+    const TOO_HIGH: &'static str = "too-high";
+    const WRONG: &'static str = "wrong";
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let verdict = match self {
+            Verdict::Confirmed => Self::CONFIRMED,
+            Verdict::TooLow => Self::TOO_LOW,
+            Verdict::TooHigh => Self::TOO_HIGH,
+            Verdict::Wrong => Self::WRONG,
+        };
+
+        f.pad(verdict)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            Self::CONFIRMED => Ok(Verdict::Confirmed),
+            Self::TOO_LOW => Ok(Verdict::TooLow),
+            Self::TOO_HIGH => Ok(Verdict::TooHigh),
+            Self::WRONG => Ok(Verdict::Wrong),
+            _ => Err(format!("Invalid verdict: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// How a freshly computed answer relates to what was submitted before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Confirmed,
+    Changed {
+        confirmed: String,
+    },
+    /// Nothing is confirmed, but the answer differs from the last run's.
+    Drifted {
+        previous: String,
+    },
+    Unconfirmed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub status: Status,
+    pub warnings: Vec<String>,
+}
+
+pub struct Ledger {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+    last_path: PathBuf,
+    last: BTreeMap<Part, String>,
+}
+
+impl Ledger {
+    /// Loads the ledger of the day in `crate_dir`, which is empty if nothing was submitted yet.
+    pub fn load(crate_dir: &Path) -> Result<Self> {
+        let path = crate_dir.join(LEDGER_FILE_NAME);
+        let last_path = crate_dir.join(LAST_ANSWERS_FILE_NAME);
+
+        let submissions = match read_if_exists(&path)? {
+            Some(contents) => {
+                parse_submissions(&contents).map_err(|e| e.with_file(path.display()))?
+            }
+            None => Vec::new(),
+        };
+
+        let last = match read_if_exists(&last_path)? {
+            Some(contents) => {
+                parse_last_answers(&contents).map_err(|e| e.with_file(last_path.display()))?
+            }
+            None => BTreeMap::new(),
+        };
+
+        Ok(Ledger {
+            path,
+            submissions,
+            last_path,
+            last,
+        })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let mut contents = String::from("# part  verdict    answer\n");
+
+        for submission in &self.submissions {
+            contents.push_str(&format!(
+                "{:<7} {:<10} {}\n",
+                submission.part, submission.verdict, submission.answer
+            ));
+        }
+
+        fs::write(&self.path, contents)
+            .map_err(|e| Error::new(format!("Failed to write {}: {}", self.path.display(), e)))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn record(&mut self, part: Part, verdict: Verdict, answer: &str) -> Result<()> {
+        validate_answer(answer)?;
+
+        self.submissions.push(Submission {
+            part,
+            verdict,
+            answer: answer.to_string(),
+        });
+
+        Ok(())
+    }
+
+    /// Answer of `part` in the last run.
+    pub fn last(&self, part: Part) -> Option<&str> {
+        self.last.get(&part).map(String::as_str)
+    }
+
+    /// Keeps `answer` as the last answer of `part`, for [`save_last`](Self::save_last).
+    pub fn remember(&mut self, part: Part, answer: &Answer) -> Result<()> {
+        let answer = answer.to_string();
+        validate_answer(&answer)?;
+        self.last.insert(part, answer);
+
+        Ok(())
+    }
+
+    pub fn save_last(&self) -> Result<()> {
+        let mut contents = String::from("# part  answer\n");
+
+        for (part, answer) in &self.last {
+            contents.push_str(&format!("{:<7} {}\n", part, answer));
+        }
+
+        fs::write(&self.last_path, contents).map_err(|e| {
+            Error::new(format!(
+                "Failed to write {}: {}",
+                self.last_path.display(),
+                e
+            ))
+        })
+    }
+
+    pub fn submissions(&self, part: Part) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter().filter(move |s| s.part == part)
+    }
+
+    /// The most recently confirmed answer of `part`.
+    pub fn confirmed(&self, part: Part) -> Option<&str> {
+        self.submissions(part)
+            .filter(|s| s.verdict == Verdict::Confirmed)
+            .last()
+            .map(|s| s.answer.as_str())
+    }
+
+    pub fn check(&self, part: Part, answer: &Answer) -> Check {
+        let answer_str = answer.to_string();
+
+        let status = match self.confirmed(part) {
+            Some(confirmed) if answer.matches(confirmed) => Status::Confirmed,
+            Some(confirmed) => Status::Changed {
+                confirmed: confirmed.to_string(),
+            },
+            None => match self.last(part) {
+                Some(previous) if !answer.matches(previous) => Status::Drifted {
+                    previous: previous.to_string(),
+                },
+                _ => Status::Unconfirmed,
+            },
+        };
+
+        let value = answer.to_bigint();
+        let mut warnings = Vec::new();
+
+        for submission in self.submissions(part) {
+            let guess = submission.answer.parse::<BigInt>().ok();
+
+            let contradiction = match (submission.verdict, &value, guess) {
+                (Verdict::Wrong, _, _) => answer.matches(&submission.answer),
+                (Verdict::TooLow, Some(value), Some(guess)) => value <= &guess,
+                (Verdict::TooHigh, Some(value), Some(guess)) => value >= &guess,
+                _ => false,
+            };
+
+            if contradiction {
+                warnings.push(format!(
+                    "part {} answer {} contradicts {} marked {}",
+                    part, answer_str, submission.answer, submission.verdict
+                ));
+            }
+        }
+
+        Check { status, warnings }
+    }
+}
+
+fn read_if_exists(path: &Path) -> Result<Option<String>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    fs::read_to_string(path)
+        .map(Some)
+        .map_err(|e| Error::new(format!("Failed to read {}: {}", path.display(), e)))
+}
+
+/// Answers are the last field of a line, so they can't be empty or contain whitespace.
+fn validate_answer(answer: &str) -> Result<()> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(format!(
+            "Answer `{}` can't be recorded, it's empty or contains whitespace",
+            answer
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn parse_submissions(contents: &str) -> Result<Vec<Submission>> {
+    let mut submissions = Vec::new();
+
+    for line in Span::new(contents).lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.as_str().starts_with('#') {
+            continue;
+        }
+
+        match line.words()[..] {
+            [part, verdict, answer] => submissions.push(Submission {
+                part: part.parse()?,
+                verdict: verdict.parse()?,
+                answer: answer.as_str().to_string(),
+            }),
+            _ => return Err(line.error("expected `<part> <verdict> <answer>`").into()),
+        }
+    }
+
+    Ok(submissions)
+}
+
+fn parse_last_answers(contents: &str) -> Result<BTreeMap<Part, String>> {
+    let mut last = BTreeMap::new();
+
+    for line in Span::new(contents).lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.as_str().starts_with('#') {
+            continue;
+        }
+
+        match line.words()[..] {
+            [part, answer] => {
+                last.insert(part.parse()?, answer.as_str().to_string());
+            }
+            _ => return Err(line.error("expected `<part> <answer>`").into()),
+        }
+    }
+
+    Ok(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger(contents: &str) -> Ledger {
+        Ledger {
+            path: PathBuf::from(LEDGER_FILE_NAME),
+            submissions: parse_submissions(contents).unwrap(),
+            last_path: PathBuf::from(LAST_ANSWERS_FILE_NAME),
+            last: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        let ledger = ledger("2 too-low 123\n2 too-high 9000\n2 wrong 500\n");
+
+        assert!(ledger
+            .check(Part::Two, &Answer::from(7380))
+            .warnings
+            .is_empty());
+        assert_eq!(
+            ledger.check(Part::Two, &Answer::from(123)).warnings.len(),
+            1
+        );
+        assert_eq!(
+            ledger.check(Part::Two, &Answer::from(9001)).warnings.len(),
+            1
+        );
+        assert_eq!(
+            ledger.check(Part::Two, &Answer::from(500)).warnings.len(),
+            1
+        );
+        assert!(ledger
+            .check(Part::One, &Answer::from(1))
+            .warnings
+            .is_empty());
+    }
+
+    #[test]
+    fn test_check_status() {
+        let ledger = ledger("1 wrong 10\n1 confirmed 42\n");
+
+        assert_eq!(
+            ledger.check(Part::One, &Answer::from(42)).status,
+            Status::Confirmed
+        );
+
+        assert_eq!(
+            ledger.check(Part::One, &Answer::from(43)).status,
+            Status::Changed {
+                confirmed: "42".to_string()
+            }
+        );
+
+        assert_eq!(
+            ledger.check(Part::Two, &Answer::from(42)).status,
+            Status::Unconfirmed
+        );
+    }
+
+    #[test]
+    fn test_check_drift() {
+        let mut ledger = ledger("1 confirmed 42\n");
+        ledger.last = parse_last_answers("# part  answer\n1       41\n2       7\n").unwrap();
+
+        assert_eq!(
+            ledger.check(Part::Two, &Answer::from(8)).status,
+            Status::Drifted {
+                previous: "7".to_string()
+            }
+        );
+        assert_eq!(
+            ledger.check(Part::Two, &Answer::from(7)).status,
+            Status::Unconfirmed
+        );
+        assert_eq!(
+            ledger.check(Part::One, &Answer::from(42)).status,
+            Status::Confirmed
+        );
+
+        ledger.remember(Part::Two, &Answer::from(8)).unwrap();
+        assert_eq!(ledger.last(Part::Two), Some("8"));
+    }
+
+    #[test]
+    fn test_reject_whitespace() {
+        let mut ledger = ledger("");
+
+        assert!(ledger.record(Part::One, Verdict::Wrong, "12 34").is_err());
+        assert!(ledger.record(Part::One, Verdict::Wrong, "").is_err());
+        assert!(ledger
+            .remember(Part::One, &Answer::Str("a b".to_string()))
+            .is_err());
+        assert!(ledger.record(Part::One, Verdict::Wrong, "1234").is_ok());
+        assert_eq!(ledger.submissions(Part::One).count(), 1);
+    }
+
+    #[test]
+    fn test_positioned_errors() {
+        let error = parse_submissions("# part verdict answer\n1 wrong 12\n  2 right\n")
+            .unwrap_err()
+            .with_file(LEDGER_FILE_NAME);
+        assert_eq!(
+            error.to_string(),
+            "ledger.txt:3:3: expected `<part> <verdict> <answer>`"
+        );
+
+        let error = parse_last_answers("1 41\n3 7\n").unwrap_err();
+        assert_eq!(error.position(), Some((2, 1)));
+    }
+}
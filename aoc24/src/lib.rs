@@ -1 +1,25 @@
+pub mod answer;
+pub mod error;
+pub mod frames;
 pub mod grid;
+pub mod input;
+pub mod ledger;
+pub mod log;
+pub mod manifest;
+pub mod math;
+pub mod memo;
+pub mod memory;
+pub mod parallel;
+pub mod parse;
+pub mod progress;
+pub mod registry;
+pub mod snapshot;
+pub mod solution;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+pub use error::{Error, Result};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
@@ -0,0 +1,227 @@
+use std::fs;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::input::{InputResolver, InputSpec};
+use crate::parse::Span;
+use crate::solution::{Part, Solution};
+
+/// Each day lists the answers expected for its inputs in this file, one per line:
+///
+/// ```text
+/// # input     part  answer
+/// example:1   1     11
+/// input       2     22539317
+/// ```
+pub const MANIFEST_FILE_NAME: &str = "answers.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expectation {
+    pub input: InputSpec,
+    pub part: Part,
+    pub answer: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Manifest {
+    expectations: Vec<Expectation>,
+}
+
+impl Manifest {
+    pub fn load(crate_dir: &Path) -> Result<Self> {
+        let path = crate_dir.join(MANIFEST_FILE_NAME);
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| Error::new(format!("Failed to read {}: {}", path.display(), e)))?;
+
+        Manifest::parse(&contents).map_err(|e| e.with_file(path.display()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut expectations = Vec::new();
+
+        for line in Span::new(contents).lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.as_str().starts_with('#') {
+                continue;
+            }
+
+            let (input, part) = match line.words()[..] {
+                [input, part, _, ..] => (input, part),
+                _ => return Err(line.error("expected `<input> <part> <answer>`").into()),
+            };
+            let (_, rest) = split_field(line.as_str());
+            let (_, answer) = split_field(rest);
+
+            let spec = input.parse::<InputSpec>()?;
+
+            if spec.file_name().is_none() {
+                return Err(input.error(format!("{} is not a named input", spec)).into());
+            }
+
+            expectations.push(Expectation {
+                input: spec,
+                part: part.parse::<Part>()?,
+                answer: answer.to_string(),
+            });
+        }
+
+        Ok(Manifest { expectations })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Expectation> {
+        self.expectations.iter()
+    }
+
+    pub fn expected(&self, input: &InputSpec, part: Part) -> Option<&str> {
+        self.expectations
+            .iter()
+            .find(|e| &e.input == input && e.part == part)
+            .map(|e| e.answer.as_str())
+    }
+
+    /// Inputs in the order they first appear in the manifest.
+    pub fn inputs(&self) -> Vec<&InputSpec> {
+        let mut inputs: Vec<&InputSpec> = Vec::new();
+
+        for expectation in &self.expectations {
+            if !inputs.contains(&&expectation.input) {
+                inputs.push(&expectation.input);
+            }
+        }
+
+        inputs
+    }
+}
+
+fn split_field(s: &str) -> (&str, &str) {
+    match s.find(char::is_whitespace) {
+        Some(i) => (&s[..i], s[i..].trim()),
+        None => (s, ""),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    Examples,
+    Real,
+}
+
+impl Scope {
+    fn contains(self, input: &InputSpec) -> bool {
+        match self {
+            Scope::Examples => matches!(input, InputSpec::Example(_)),
+            Scope::Real => matches!(input, InputSpec::Real),
+        }
+    }
+}
+
+/// Runs `S` against every input of `scope` listed in the day's manifest and panics with all
+/// mismatches at once. Missing real inputs are skipped since not everyone has them.
+pub fn check<S: Solution>(crate_dir: &str, scope: Scope) {
+    let crate_dir = Path::new(crate_dir);
+    let manifest = Manifest::load(crate_dir).unwrap();
+    let resolver = InputResolver::new(crate_dir);
+    let mut failures = Vec::new();
+
+    if scope == Scope::Examples {
+        for variant in resolver.examples() {
+            let input = InputSpec::Example(variant);
+
+            if !manifest.inputs().contains(&&input) {
+                failures.push(format!("{} has no expected answers", input));
+            }
+        }
+    }
+
+    for input in manifest.inputs().into_iter().filter(|i| scope.contains(i)) {
+        let contents = match resolver.read(input) {
+            Ok(contents) => contents,
+            Err(error) if scope == Scope::Real => {
+                eprintln!("Skipping day {} {}: {}", S::DAY, input, error);
+                continue;
+            }
+            Err(error) => {
+                failures.push(error.to_string());
+                continue;
+            }
+        };
+
+        let parsed = match S::parse(&contents) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                failures.push(format!("{}: {}", input, error));
+                continue;
+            }
+        };
+
+        for expectation in manifest.iter().filter(|e| &e.input == input) {
+            let actual = match S::solve(&parsed, expectation.part) {
+                Ok(answer) if answer.matches(&expectation.answer) => continue,
+                Ok(answer) => answer.to_string(),
+                Err(error) => format!("error ({})", error),
+            };
+
+            failures.push(format!(
+                "{} part {}: expected {}, got {}",
+                input, expectation.part, expectation.answer, actual
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Day {} answers don't match {}:\n  {}",
+        S::DAY,
+        MANIFEST_FILE_NAME,
+        failures.join("\n  ")
+    );
+}
+
+/// Generates the manifest tests for a day, meant for the day's `tests/answers.rs`.
+#[macro_export]
+macro_rules! answer_tests {
+    ($solution:ty) => {
+        #[test]
+        fn examples_match_manifest() {
+            $crate::manifest::check::<$solution>(
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::manifest::Scope::Examples,
+            );
+        }
+
+        #[test]
+        fn real_input_matches_manifest() {
+            $crate::manifest::check::<$solution>(
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::manifest::Scope::Real,
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let manifest = Manifest::parse("# input part answer\nexample:1   2   a b\n").unwrap();
+        assert_eq!(
+            manifest.expected(&InputSpec::Example(1), Part::Two),
+            Some("a b")
+        );
+
+        let error = Manifest::parse("input 1 5\nexample:1   3   7\n")
+            .unwrap_err()
+            .with_file(MANIFEST_FILE_NAME);
+        assert!(
+            error.to_string().starts_with("answers.txt:2:13: "),
+            "{}",
+            error
+        );
+
+        let error = Manifest::parse("\n  example:1 2\n").unwrap_err();
+        assert_eq!(error.position(), Some((2, 3)));
+    }
+}
@@ -0,0 +1,314 @@
+//! Number theory for the puzzles that need it: divisors, modular arithmetic, the Chinese
+//! Remainder Theorem, decimal digits and small linear systems. Products are computed in 128 bits,
+//! so nothing overflows for operands and moduli that fit in 64 bits.
+
+/// Greatest common divisor. It's unsigned since `gcd(i64::MIN, 0)` is 2^63, one more than any
+/// `i64`. `gcd(0, 0)` is 0.
+pub fn gcd(a: i64, b: i64) -> u64 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Least common multiple, never negative. 0 if either argument is 0, overflows like `i64::abs`
+/// when the result doesn't fit.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    (a / gcd(a, b) as i64 * b).abs()
+}
+
+/// Extended Euclid: `(g, x, y)` such that `a * x + b * y == g`, with `g` the non-negative gcd.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..m` such that `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = egcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `base^exp mod m`, by repeated squaring.
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> u64 {
+    assert!(m > 0, "modulus must be positive");
+
+    let m = m as u128;
+    let mut base = base as u128 % m;
+    let mut result = 1 % m;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+
+        base = base * base % m;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+/// Smallest non-negative `x` with `x ≡ r (mod m)` for every `(r, m)`, together with the combined
+/// modulus. The moduli don't need to be coprime, `None` means the congruences contradict each
+/// other.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x: i128 = 0;
+    let mut modulus: i128 = 1;
+
+    for &(r, m) in congruences {
+        if m <= 0 {
+            return None;
+        }
+
+        let (r, m) = ((r as i128).rem_euclid(m as i128), m as i128);
+        let (g, p, _) = egcd(modulus as i64, m as i64);
+        let g = g as i128;
+
+        if (r - x) % g != 0 {
+            return None;
+        }
+
+        // x + modulus * k ≡ r (mod m), solved for k
+        let step = m / g;
+        let k = ((r - x) / g % step * p as i128).rem_euclid(step);
+
+        x += modulus * k;
+        modulus *= step;
+        x = x.rem_euclid(modulus);
+    }
+
+    Some((i64::try_from(x).ok()?, i64::try_from(modulus).ok()?))
+}
+
+/// Number of decimal digits, 1 for 0.
+pub fn digits(n: u64) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// Splits off the last `low_digits` decimal digits, e.g. `split_digits(253000, 3) == (253, 0)`.
+pub fn split_digits(n: u64, low_digits: u32) -> (u64, u64) {
+    match 10u64.checked_pow(low_digits) {
+        Some(divisor) => (n / divisor, n % divisor),
+        None => (0, n),
+    }
+}
+
+/// Integer solution of `a[0][0] * x + a[0][1] * y = b[0]` and `a[1][0] * x + a[1][1] * y = b[1]`.
+/// `None` if the system has no unique solution or it isn't a whole number.
+pub fn solve_2x2(a: [[i64; 2]; 2], b: [i64; 2]) -> Option<(i64, i64)> {
+    let [[a00, a01], [a10, a11]] = a.map(|row| row.map(i128::from));
+    let [b0, b1] = b.map(i128::from);
+
+    let det = a00 * a11 - a01 * a10;
+
+    if det == 0 {
+        return None;
+    }
+
+    let x = b0 * a11 - a01 * b1;
+    let y = a00 * b1 - b0 * a10;
+
+    if x % det != 0 || y % det != 0 {
+        return None;
+    }
+
+    Some((i64::try_from(x / det).ok()?, i64::try_from(y / det).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RANGE: std::ops::RangeInclusive<i64> = -40..=40;
+
+    fn naive_gcd(a: i64, b: i64) -> i64 {
+        (1..=a.abs().max(b.abs()))
+            .rev()
+            .find(|d| a % d == 0 && b % d == 0)
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_gcd_lcm_egcd() {
+        for a in RANGE {
+            for b in RANGE {
+                let g = gcd(a, b) as i64;
+                assert_eq!(g, naive_gcd(a, b), "gcd({}, {})", a, b);
+
+                let (eg, x, y) = egcd(a, b);
+                assert_eq!(eg, g, "egcd({}, {})", a, b);
+                assert_eq!(a * x + b * y, g, "egcd({}, {}) coefficients", a, b);
+
+                let l = lcm(a, b);
+                if a == 0 || b == 0 {
+                    assert_eq!(l, 0);
+                } else {
+                    assert_eq!(l * g, (a * b).abs(), "lcm({}, {})", a, b);
+                }
+            }
+        }
+
+        assert_eq!(gcd(i64::MIN, 0), 1 << 63);
+        assert_eq!(gcd(i64::MIN, i64::MIN), 1 << 63);
+        assert_eq!(gcd(i64::MIN, 6), 2);
+    }
+
+    #[test]
+    fn test_mod_inv() {
+        for m in 1..=60i64 {
+            for a in -60..=60 {
+                let expected = (0..m).find(|x| (a * x).rem_euclid(m) == 1 % m);
+                assert_eq!(mod_inv(a, m), expected, "mod_inv({}, {})", a, m);
+            }
+        }
+
+        assert_eq!(mod_inv(3, 0), None);
+        assert_eq!(mod_inv(3, -7), None);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        for m in 1..=30u64 {
+            for base in 0..=30u64 {
+                let mut expected = 1 % m;
+
+                for exp in 0..=20u64 {
+                    assert_eq!(
+                        mod_pow(base, exp, m),
+                        expected,
+                        "{}^{} mod {}",
+                        base,
+                        exp,
+                        m
+                    );
+                    expected = expected * base % m;
+                }
+            }
+        }
+
+        // Fermat's little theorem for a large prime, which needs 128-bit products
+        let p = 18446744073709551557;
+        assert_eq!(mod_pow(123456789, p - 1, p), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        for m1 in 1..=12 {
+            for m2 in 1..=12 {
+                for r1 in -3..m1 {
+                    for r2 in 0..m2 {
+                        let modulus = lcm(m1, m2);
+                        let expected = (0..modulus)
+                            .find(|x| (x - r1).rem_euclid(m1) == 0 && (x - r2) % m2 == 0)
+                            .map(|x| (x, modulus));
+
+                        assert_eq!(
+                            crt(&[(r1, m1), (r2, m2)]),
+                            expected,
+                            "x ≡ {} (mod {}), x ≡ {} (mod {})",
+                            r1,
+                            m1,
+                            r2,
+                            m2
+                        );
+                    }
+                }
+            }
+        }
+
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 0)]), None);
+        assert_eq!(crt(&[(0, 101), (0, 103)]), Some((0, 10403)));
+    }
+
+    #[test]
+    fn test_digits() {
+        for n in 0..=100_000u64 {
+            assert_eq!(digits(n) as usize, n.to_string().len(), "digits({})", n);
+        }
+
+        assert_eq!(digits(u64::MAX), 20);
+
+        for n in [0, 7, 1234, 253000, 1000000, u64::MAX] {
+            for low_digits in 0..=21 {
+                let (high, low) = split_digits(n, low_digits);
+                let text = n.to_string();
+                let at = text.len().saturating_sub(low_digits as usize);
+
+                assert_eq!(
+                    high,
+                    text[..at].parse().unwrap_or(0),
+                    "{} at {}",
+                    n,
+                    low_digits
+                );
+                assert_eq!(
+                    low,
+                    text[at..].parse().unwrap_or(0),
+                    "{} at {}",
+                    n,
+                    low_digits
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_2x2() {
+        let values = -3..=3;
+
+        for a00 in values.clone() {
+            for a01 in values.clone() {
+                for a10 in values.clone() {
+                    for a11 in values.clone() {
+                        let a = [[a00, a01], [a10, a11]];
+                        let singular = a00 * a11 == a01 * a10;
+
+                        for x in values.clone() {
+                            for y in values.clone() {
+                                let b = [a00 * x + a01 * y, a10 * x + a11 * y];
+                                let expected = (!singular).then_some((x, y));
+
+                                assert_eq!(solve_2x2(a, b), expected, "{:?} {:?}", a, b);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // The claw machine example from 2024 day 13
+        assert_eq!(
+            solve_2x2([[94, 22], [34, 67]], [8400, 5400]),
+            Some((80, 40))
+        );
+        assert_eq!(solve_2x2([[26, 67], [66, 21]], [12748, 12176]), None);
+    }
+}
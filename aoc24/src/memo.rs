@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Cache for a recursive function, keyed by its arguments. The function gets the cache back so
+/// its recursive calls go through it too:
+///
+/// ```
+/// use aoc24::memo::Memo;
+///
+/// fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     match n {
+///         0 | 1 => n,
+///         _ => memo.call(n - 1, fib) + memo.call(n - 2, fib),
+///     }
+/// }
+///
+/// assert_eq!(Memo::new().call(90, fib), 2880067194370816120);
+/// ```
+///
+/// A cache with a limit is cleared once it holds that many entries, which bounds its memory at
+/// the cost of recomputing values that were still needed.
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    limit: Option<usize>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            limit: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn with_limit(limit: usize) -> Self {
+        Memo {
+            limit: Some(limit.max(1)),
+            ..Self::new()
+        }
+    }
+
+    /// Returns `f(self, key)`, computing it only if the key isn't cached yet.
+    pub fn call<F>(&mut self, key: K, f: F) -> V
+    where
+        F: Fn(&mut Self, K) -> V + Copy,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = f(self, key.clone());
+
+        if self.limit.is_some_and(|limit| self.cache.len() >= limit) {
+            self.cache.clear();
+        }
+
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(memo: &mut Memo<(u32, u32), u64>, (x, y): (u32, u32)) -> u64 {
+        if x == 0 || y == 0 {
+            return 1;
+        }
+
+        memo.call((x - 1, y), paths) + memo.call((x, y - 1), paths)
+    }
+
+    #[test]
+    fn test_call() {
+        let mut memo = Memo::new();
+
+        assert_eq!(memo.call((16, 16), paths), 601080390);
+        assert_eq!(memo.misses(), 16 * 16 + 2 * 16);
+        assert!(memo.hits() > 0);
+    }
+
+    #[test]
+    fn test_limit() {
+        let mut memo = Memo::with_limit(10);
+
+        assert_eq!(memo.call((8, 8), paths), 12870);
+        assert!(memo.len() <= 10);
+    }
+}
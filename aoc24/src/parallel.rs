@@ -0,0 +1,124 @@
+//! Helpers for embarrassingly parallel searches. With the `parallel` feature they run on rayon's
+//! thread pool, otherwise on the current thread. Either way results come back in input order, so
+//! answers don't depend on how the work was scheduled.
+
+use std::ops::Range;
+
+pub use imp::{map, map_range, set_threads, threads};
+
+pub const ENABLED: bool = cfg!(feature = "parallel");
+
+#[cfg(feature = "parallel")]
+mod imp {
+    use std::ops::Range;
+
+    use rayon::prelude::*;
+
+    /// Sets the number of worker threads. Must be called before any parallel work starts.
+    pub fn set_threads(threads: usize) -> Result<(), String> {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|e| format!("Failed to start {} threads: {}", threads, e))
+    }
+
+    pub fn threads() -> usize {
+        rayon::current_num_threads()
+    }
+
+    pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync + Send,
+    {
+        items.par_iter().map(f).collect()
+    }
+
+    pub fn map_range<R, F>(range: Range<usize>, f: F) -> Vec<R>
+    where
+        R: Send,
+        F: Fn(usize) -> R + Sync + Send,
+    {
+        range.into_par_iter().map(f).collect()
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+mod imp {
+    use std::ops::Range;
+
+    pub fn set_threads(threads: usize) -> Result<(), String> {
+        if threads > 1 {
+            crate::warn!("built without the parallel feature, running on a single thread");
+        }
+
+        Ok(())
+    }
+
+    pub fn threads() -> usize {
+        1
+    }
+
+    pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync + Send,
+    {
+        items.iter().map(f).collect()
+    }
+
+    pub fn map_range<R, F>(range: Range<usize>, f: F) -> Vec<R>
+    where
+        R: Send,
+        F: Fn(usize) -> R + Sync + Send,
+    {
+        range.map(f).collect()
+    }
+}
+
+/// Smallest index in `range` matching `predicate`. Indices are checked in chunks of
+/// `chunk_size` so the search can stop early without racing to a non-deterministic answer.
+pub fn find_first<F>(range: Range<usize>, chunk_size: usize, predicate: F) -> Option<usize>
+where
+    F: Fn(usize) -> bool + Sync + Send,
+{
+    let mut start = range.start;
+
+    while start < range.end {
+        let end = (start + chunk_size.max(1)).min(range.end);
+
+        let found = map_range(start..end, |i| predicate(i).then_some(i))
+            .into_iter()
+            .flatten()
+            .next();
+
+        if found.is_some() {
+            return found;
+        }
+
+        start = end;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_first() {
+        assert_eq!(find_first(0..1000, 7, |i| i * i > 500), Some(23));
+        assert_eq!(find_first(0..1000, 1000, |i| i % 100 == 99), Some(99));
+        assert_eq!(find_first(0..10, 3, |_| false), None);
+        assert_eq!(find_first(5..5, 3, |_| true), None);
+    }
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items = (0..100).collect::<Vec<u32>>();
+        assert_eq!(map(&items, |i| i * 2), map_range(0..100, |i| i as u32 * 2));
+    }
+}
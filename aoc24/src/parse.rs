@@ -0,0 +1,446 @@
+//! Helpers for the usual shapes of puzzle input. Everything works on [`Span`]s, slices of the
+//! input that remember where they start, so a bad value is reported with its line and column
+//! instead of a bare `unwrap` panic.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// 1-based line of the input the error points at.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 1-based column, counted in characters.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// A slice of the input together with the line and column it starts at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Span {
+            text,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// An error pointing at the start of the span.
+    pub fn error(&self, message: impl Display) -> ParseError {
+        ParseError::new(self.line, self.column, message.to_string())
+    }
+
+    /// The span of `text[start..end]`.
+    fn slice(&self, start: usize, end: usize) -> Span<'a> {
+        self.advance(start).truncate(end - start)
+    }
+
+    fn advance(&self, offset: usize) -> Span<'a> {
+        let (line, column) = position_after(self.line, self.column, &self.text[..offset]);
+
+        Span {
+            text: &self.text[offset..],
+            line,
+            column,
+        }
+    }
+
+    fn truncate(&self, len: usize) -> Span<'a> {
+        Span {
+            text: &self.text[..len],
+            ..*self
+        }
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+
+        self.slice(start, end)
+    }
+
+    /// Splits on every occurrence of `separator`, keeping empty pieces.
+    pub fn split(&self, separator: &str) -> Vec<Span<'a>> {
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+
+        while let Some(i) = rest.text.find(separator) {
+            pieces.push(rest.truncate(i));
+            rest = rest.advance(i + separator.len());
+        }
+
+        pieces.push(rest);
+        pieces
+    }
+
+    /// Splits at the first `separator`, which has to be there.
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>)> {
+        let i = self
+            .text
+            .find(separator)
+            .ok_or_else(|| self.error(format!("expected `{}` in `{}`", separator, self.text)))?;
+
+        Ok((self.truncate(i), self.advance(i + separator.len())))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>> {
+        if self.text.starts_with(prefix) {
+            Ok(self.advance(prefix.len()))
+        } else {
+            Err(self.error(format!("expected `{}` at `{}`", prefix, self.text)))
+        }
+    }
+
+    /// Lines without their line break. A trailing line break doesn't start another line.
+    pub fn lines(&self) -> Vec<Span<'a>> {
+        let mut lines = self.split("\n");
+
+        if lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+
+        lines
+            .into_iter()
+            .map(|line| line.truncate(line.text.trim_end_matches('\r').len()))
+            .collect()
+    }
+
+    /// Groups of lines separated by blank lines, without the blank lines themselves.
+    pub fn sections(&self) -> Vec<Span<'a>> {
+        let mut sections = Vec::new();
+        let mut current: Option<(usize, usize)> = None;
+
+        for line in self.lines() {
+            let start = self.offset_of(&line);
+
+            if line.text.trim().is_empty() {
+                if let Some((first, last)) = current.take() {
+                    sections.push(self.slice(first, last));
+                }
+            } else {
+                let end = start + line.text.len();
+                current = Some(current.map_or((start, end), |(first, _)| (first, end)));
+            }
+        }
+
+        if let Some((first, last)) = current {
+            sections.push(self.slice(first, last));
+        }
+
+        sections
+    }
+
+    fn offset_of(&self, inner: &Span<'a>) -> usize {
+        inner.text.as_ptr() as usize - self.text.as_ptr() as usize
+    }
+
+    /// Pieces separated by runs of whitespace.
+    pub fn words(&self) -> Vec<Span<'a>> {
+        let mut words = Vec::new();
+        let mut rest = *self;
+
+        loop {
+            let trimmed = rest.text.trim_start();
+
+            if trimmed.is_empty() {
+                return words;
+            }
+
+            rest = rest.advance(rest.text.len() - trimmed.len());
+
+            let len = rest
+                .text
+                .find(char::is_whitespace)
+                .unwrap_or(rest.text.len());
+
+            words.push(rest.truncate(len));
+            rest = rest.advance(len);
+        }
+    }
+
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text
+            .parse()
+            .map_err(|e| self.error(format!("invalid value `{}`: {}", self.text, e)))
+    }
+
+    /// Parses `<key><separator><value>`, trimming both sides.
+    pub fn key_value<K, V>(&self, separator: &str) -> Result<(K, V)>
+    where
+        K: FromStr,
+        K::Err: Display,
+        V: FromStr,
+        V::Err: Display,
+    {
+        let (key, value) = self.split_once(separator)?;
+        Ok((key.trim().parse()?, value.trim().parse()?))
+    }
+
+    /// Parses every piece between `separator`s, trimming each one.
+    pub fn list<T>(&self, separator: &str) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.split(separator)
+            .iter()
+            .map(|piece| piece.trim().parse())
+            .collect()
+    }
+
+    /// Parses every whitespace separated word.
+    pub fn parse_words<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.words().iter().map(|word| word.parse()).collect()
+    }
+
+    /// Like [`Span::list`], but there have to be exactly `N` pieces.
+    pub fn array<T, const N: usize>(&self, separator: &str) -> Result<[T; N]>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let values = self.list::<T>(separator)?;
+        let count = values.len();
+
+        values.try_into().map_err(|_| {
+            self.error(format!(
+                "expected {} values separated by `{}`, found {}",
+                N, separator, count
+            ))
+        })
+    }
+
+    /// Every integer in the text, ignoring whatever is around them. A `-` directly in front of
+    /// digits is taken as the sign, so `p=3,-4` yields 3 and -4.
+    pub fn ints<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut values = Vec::new();
+        let mut rest = *self;
+
+        loop {
+            let bytes = rest.text.as_bytes();
+
+            let is_start = |i: usize| {
+                bytes[i].is_ascii_digit()
+                    || (bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+            };
+
+            let Some(start) = (0..bytes.len()).find(|&i| is_start(i)) else {
+                break;
+            };
+
+            let end = (start + 1..bytes.len())
+                .find(|&i| !bytes[i].is_ascii_digit())
+                .unwrap_or(bytes.len());
+
+            values.push(rest.slice(start, end).parse()?);
+            rest = rest.advance(end);
+        }
+
+        Ok(values)
+    }
+
+    /// Applies `f` to every non-blank line.
+    pub fn map_lines<T>(&self, f: impl FnMut(Span<'a>) -> Result<T>) -> Result<Vec<T>> {
+        self.lines()
+            .into_iter()
+            .filter(|line| !line.text.trim().is_empty())
+            .map(f)
+            .collect()
+    }
+
+    /// Splits into exactly `N` blank-line separated sections.
+    pub fn sections_array<const N: usize>(&self) -> Result<[Span<'a>; N]> {
+        let sections = self.sections();
+        let count = sections.len();
+
+        sections.try_into().map_err(|_| {
+            self.error(format!(
+                "expected {} sections separated by blank lines, found {}",
+                N, count
+            ))
+        })
+    }
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(self.text)
+    }
+}
+
+fn position_after(mut line: usize, mut column: usize, text: &str) -> (usize, usize) {
+    for c in text.chars() {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    (line, column)
+}
+
+/// Every integer in `input`, see [`Span::ints`].
+pub fn ints<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    Span::new(input).ints()
+}
+
+/// Applies `f` to every non-blank line of `input`.
+pub fn lines<'a, T>(input: &'a str, f: impl FnMut(Span<'a>) -> Result<T>) -> Result<Vec<T>> {
+    Span::new(input).map_lines(f)
+}
+
+/// Blank-line separated sections of `input`.
+pub fn sections(input: &str) -> Vec<Span<'_>> {
+    Span::new(input).sections()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(span: Span) -> (usize, usize) {
+        (span.line(), span.column())
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i32>("p=0,4 v=3,-3").unwrap(), vec![0, 4, 3, -3]);
+        assert_eq!(ints::<i64>("190: 10 19").unwrap(), vec![190, 10, 19]);
+        assert_eq!(ints::<i32>("a-b - 7-8").unwrap(), vec![7, -8]);
+        assert!(ints::<i32>("no numbers").unwrap().is_empty());
+
+        let error = ints::<u8>("1 2\n3 300").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 3));
+    }
+
+    #[test]
+    fn test_lines_and_sections() {
+        let input = "47|53\n97|13\n\n\n75,47,61\r\n97,61\n";
+        let sections = sections(input);
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].as_str(), "47|53\n97|13");
+        assert_eq!(sections[1].as_str(), "75,47,61\r\n97,61");
+        assert_eq!(position(sections[1]), (5, 1));
+
+        let lines = sections[1].lines();
+        assert_eq!(lines[0].as_str(), "75,47,61");
+        assert_eq!(position(lines[1]), (6, 1));
+
+        assert!(Span::new(input).sections_array::<3>().is_err());
+    }
+
+    #[test]
+    fn test_split_positions() {
+        let span = Span::new("a\n  1, 2,x");
+        let pieces = span.lines()[1].split(",");
+
+        assert_eq!(pieces.len(), 3);
+        assert_eq!(position(pieces[1].trim()), (2, 6));
+
+        let error = span.lines()[1].list::<i32>(",").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 8));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 8: invalid value `x`: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_combinators() {
+        let line = Span::new("3267: 81 40 27");
+        let (value, operands) = line.split_once(":").unwrap();
+
+        assert_eq!(value.parse::<i64>().unwrap(), 3267);
+        assert_eq!(operands.parse_words::<i64>().unwrap(), vec![81, 40, 27]);
+        assert_eq!(
+            Span::new("47|53").key_value::<i32, i32>("|").unwrap(),
+            (47, 53)
+        );
+        assert_eq!(Span::new("3,-3").array::<i32, 2>(",").unwrap(), [3, -3]);
+        assert!(Span::new("1,2,3").array::<i32, 2>(",").is_err());
+
+        let error = Span::new("x=1").strip_prefix("p=").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+
+        let words = lines("1 2\n\n 3  4\n", |line| line.parse_words::<u32>()).unwrap();
+        assert_eq!(words, vec![vec![1, 2], vec![3, 4]]);
+    }
+}
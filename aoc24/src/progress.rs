@@ -0,0 +1,150 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::log::{self, Level};
+
+/// Tracks how far a long-running search got. `inc` only needs `&self`, so a single `Progress`
+/// can be shared between parallel workers.
+///
+/// On a terminal a single line on stderr is redrawn in place, otherwise a log line is emitted
+/// every `LOG_INTERVAL`. Like other diagnostics it's off by default and only shown from the
+/// `Debug` level on, e.g. with `-v`.
+pub struct Progress {
+    label: String,
+    total: u64,
+    done: AtomicU64,
+    started: Instant,
+    last_report: Mutex<Instant>,
+    is_tty: bool,
+}
+
+impl Progress {
+    const DRAW_INTERVAL: Duration = Duration::from_millis(100);
+    const LOG_INTERVAL: Duration = Duration::from_secs(5);
+    const BAR_WIDTH: usize = 24;
+    const LEVEL: Level = Level::Debug;
+
+    pub fn new(label: impl Into<String>, total: u64) -> Self {
+        let started = Instant::now();
+
+        Progress {
+            label: label.into(),
+            total,
+            done: AtomicU64::new(0),
+            started,
+            last_report: Mutex::new(started),
+            is_tty: io::stderr().is_terminal(),
+        }
+    }
+
+    pub fn inc(&self, steps: u64) {
+        self.done.fetch_add(steps, Ordering::Relaxed);
+        self.report();
+    }
+
+    pub fn position(&self) -> u64 {
+        self.done.load(Ordering::Relaxed)
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            return 1.0;
+        }
+
+        (self.position() as f64 / self.total as f64).min(1.0)
+    }
+
+    /// Steps per second since the progress was created.
+    pub fn throughput(&self) -> f64 {
+        let elapsed = self.started.elapsed().as_secs_f64();
+
+        if elapsed == 0.0 {
+            return 0.0;
+        }
+
+        self.position() as f64 / elapsed
+    }
+
+    /// Estimated time until `total` is reached, assuming the throughput stays the same.
+    pub fn eta(&self) -> Option<Duration> {
+        let throughput = self.throughput();
+
+        if throughput == 0.0 {
+            return None;
+        }
+
+        let remaining = self.total.saturating_sub(self.position());
+        Some(Duration::from_secs_f64(remaining as f64 / throughput))
+    }
+
+    pub fn finish(&self) {
+        if self.is_tty && log::enabled(Self::LEVEL) {
+            eprint!("\r\x1b[2K");
+            let _ = io::stderr().flush();
+        }
+    }
+
+    fn report(&self) {
+        if !log::enabled(Self::LEVEL) {
+            return;
+        }
+
+        let interval = if self.is_tty {
+            Self::DRAW_INTERVAL
+        } else {
+            Self::LOG_INTERVAL
+        };
+
+        // Workers that can't get the lock skip drawing rather than wait for it
+        let Ok(mut last_report) = self.last_report.try_lock() else {
+            return;
+        };
+
+        if last_report.elapsed() < interval {
+            return;
+        }
+
+        *last_report = Instant::now();
+
+        if self.is_tty {
+            eprint!("\r\x1b[2K{}", self.status_line());
+            let _ = io::stderr().flush();
+        } else {
+            crate::debug!("{}", self.status_line());
+        }
+    }
+
+    fn status_line(&self) -> String {
+        let fraction = self.fraction();
+        let filled = (fraction * Self::BAR_WIDTH as f64) as usize;
+
+        let eta = self
+            .eta()
+            .map(|eta| format!("{:.0?}", eta))
+            .unwrap_or_else(|| "?".to_string());
+
+        format!(
+            "{} [{}{}] {:.1}% {}/{} {:.0}/s ETA {}",
+            self.label,
+            "#".repeat(filled),
+            ".".repeat(Self::BAR_WIDTH - filled),
+            fraction * 100.0,
+            self.position(),
+            self.total,
+            self.throughput(),
+            eta
+        )
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
@@ -0,0 +1,124 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::frames::FrameRecorder;
+use crate::input::InputResolver;
+use crate::solution::{Part, Solution};
+
+/// Parsed input of a registered day, opaque to everything but the day that produced it.
+pub struct Parsed(Box<dyn Any>);
+
+/// Type-erased handle to a day's `Solution`, so days with different input types can be driven
+/// from the same place.
+pub struct Entry {
+    day: u8,
+    title: &'static str,
+    dir: &'static str,
+    parse: fn(&str) -> Result<Parsed>,
+    solve: fn(&Parsed, Part) -> Result<Answer>,
+    record: fn(&Parsed, Part, &mut FrameRecorder) -> Result<()>,
+}
+
+impl Entry {
+    pub fn new<S: Solution>(dir: &'static str) -> Self {
+        Entry {
+            day: S::DAY,
+            title: S::TITLE,
+            dir,
+            parse: parse_erased::<S>,
+            solve: solve_erased::<S>,
+            record: record_erased::<S>,
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    /// Directory of the day's crate, which is where its inputs live.
+    pub fn dir(&self) -> &'static Path {
+        Path::new(self.dir)
+    }
+
+    pub fn inputs(&self) -> InputResolver {
+        InputResolver::new(self.dir)
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed> {
+        (self.parse)(input)
+    }
+
+    pub fn solve(&self, parsed: &Parsed, part: Part) -> Result<Answer> {
+        (self.solve)(parsed, part)
+    }
+
+    pub fn record(&self, parsed: &Parsed, part: Part, recorder: &mut FrameRecorder) -> Result<()> {
+        (self.record)(parsed, part, recorder)
+    }
+}
+
+fn parse_erased<S: Solution>(input: &str) -> Result<Parsed> {
+    S::parse(input)
+        .map(|input| Parsed(Box::new(input)))
+        .map_err(|e| e.with_day(S::DAY))
+}
+
+fn solve_erased<S: Solution>(parsed: &Parsed, part: Part) -> Result<Answer> {
+    S::solve(downcast::<S>(parsed), part).map_err(|e| e.with_day(S::DAY))
+}
+
+fn record_erased<S: Solution>(
+    parsed: &Parsed,
+    part: Part,
+    recorder: &mut FrameRecorder,
+) -> Result<()> {
+    S::record(downcast::<S>(parsed), part, recorder).map_err(|e| e.with_day(S::DAY))
+}
+
+fn downcast<S: Solution>(parsed: &Parsed) -> &S::Input {
+    parsed
+        .0
+        .downcast_ref::<S::Input>()
+        .expect("parsed input belongs to another day")
+}
+
+#[derive(Default)]
+pub struct Registry {
+    entries: BTreeMap<u8, Entry>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Days pass their own `env!("CARGO_MANIFEST_DIR")` so inputs can be found regardless of the
+    /// current directory.
+    pub fn register<S: Solution>(&mut self, dir: &'static str) {
+        let previous = self.entries.insert(S::DAY, Entry::new::<S>(dir));
+        assert!(previous.is_none(), "day {} registered twice", S::DAY);
+    }
+
+    pub fn get(&self, day: u8) -> Option<&Entry> {
+        self.entries.get(&day)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -0,0 +1,110 @@
+//! Golden file tests for rendered output. The expected render of each snapshot is kept next to
+//! the day's tests in `tests/snapshots/<name>.txt`, so any change to it, including one caused by
+//! the `Display` of a shared type like [`Grid`](crate::grid::Grid), shows up in review.
+//!
+//! Run the tests with `AOC_UPDATE_SNAPSHOTS=1` to write new and changed renders instead of
+//! failing on them.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use similar::TextDiff;
+
+use crate::input::{InputResolver, InputSpec};
+use crate::solution::Solution;
+
+pub const UPDATE_VAR: &str = "AOC_UPDATE_SNAPSHOTS";
+
+const SNAPSHOT_DIR: &str = "tests/snapshots";
+
+pub fn snapshot_path(crate_dir: &str, name: &str) -> PathBuf {
+    Path::new(crate_dir)
+        .join(SNAPSHOT_DIR)
+        .join(format!("{}.txt", name))
+}
+
+/// Panics with a diff if `actual` isn't the stored render, or updates the stored render in
+/// update mode. Missing snapshots fail too, so they can't silently pass on CI.
+pub fn check(crate_dir: &str, name: &str, actual: &str) {
+    let path = snapshot_path(crate_dir, name);
+    let expected = fs::read_to_string(&path).ok();
+
+    if expected.as_deref() == Some(actual) {
+        return;
+    }
+
+    if is_update_mode() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual)
+            .unwrap_or_else(|e| panic!("Failed to write {}: {}", path.display(), e));
+        eprintln!("Updated snapshot {}", path.display());
+        return;
+    }
+
+    match expected {
+        None => panic!(
+            "Snapshot {} doesn't exist yet, run with {}=1 to create it",
+            path.display(),
+            UPDATE_VAR
+        ),
+        Some(expected) => panic!(
+            "Snapshot {} doesn't match, run with {}=1 to accept the change:\n{}",
+            path.display(),
+            UPDATE_VAR,
+            diff(&expected, actual)
+        ),
+    }
+}
+
+fn is_update_mode() -> bool {
+    env::var_os(UPDATE_VAR).is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+fn diff(expected: &str, actual: &str) -> String {
+    TextDiff::from_lines(expected, actual)
+        .unified_diff()
+        .context_radius(3)
+        .header("expected", "actual")
+        .to_string()
+}
+
+/// The first example of the crate in `crate_dir`, parsed by `S`, which is what most snapshots
+/// render.
+pub fn example<S: Solution>(crate_dir: &str) -> S::Input {
+    let spec = InputSpec::Example(1);
+    let input = InputResolver::new(crate_dir)
+        .read(&spec)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", spec, e));
+
+    S::parse(&input).unwrap_or_else(|e| panic!("Failed to parse {}: {}", spec, e))
+}
+
+/// Parses the first example of the calling crate with the given solution.
+#[macro_export]
+macro_rules! snapshot_example {
+    ($solution:ty) => {
+        $crate::snapshot::example::<$solution>(env!("CARGO_MANIFEST_DIR"))
+    };
+}
+
+/// Compares a render against the snapshot of the same name in the calling crate.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        $crate::snapshot::check(env!("CARGO_MANIFEST_DIR"), $name, &$actual.to_string())
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let diff = diff("#..\n.#.\n..#\n", "#..\n.O.\n..#\n");
+
+        assert!(diff.starts_with("--- expected\n+++ actual\n"));
+        assert!(diff.contains("-.#.\n+.O.\n"));
+    }
+}
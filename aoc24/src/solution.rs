@@ -0,0 +1,100 @@
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::frames::FrameRecorder;
+use crate::input::{InputResolver, InputSpec};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(&self.number().to_string())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part: {} (expected 1 or 2)", s)),
+        }
+    }
+}
+
+/// A single day's puzzle, split into a parsing phase and the two parts that share its output.
+///
+/// Malformed input is reported as an `Error` rather than a panic. The caller adds the day and
+/// the input file to it, so days only need to say what is wrong and, when parsing, where.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    /// Days that have not solved the second part yet can rely on this default.
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn solve(input: &Self::Input, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Replays the simulation behind a part into `recorder`, for days that have one to watch.
+    fn record(_input: &Self::Input, part: Part, _recorder: &mut FrameRecorder) -> Result<()> {
+        Err(Error::new(format!(
+            "Part {} has no simulation to record",
+            part
+        )))
+    }
+}
+
+/// Entry point for a day's own binary. The first argument selects the input, see `InputSpec`.
+pub fn run<S: Solution>(crate_dir: &str) -> Result<()> {
+    let spec = match env::args().nth(1) {
+        Some(arg) => arg.parse::<InputSpec>()?,
+        None => InputSpec::default(),
+    };
+
+    let context = |error: Error| error.with_file(spec.source_name()).with_day(S::DAY);
+
+    let contents = InputResolver::new(crate_dir).read(&spec)?;
+    let input = S::parse(&contents).map_err(context)?;
+
+    println!("Day {}: {}", S::DAY, S::TITLE);
+
+    for part in Part::ALL {
+        let answer = S::solve(&input, part).map_err(context)?;
+        println!("Solution (Part {}): {}", part, answer);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! Property test helpers shared by the days' tests. Only built with the `testing` feature, which
+//! the days enable for their dev-dependency on aoc24, so proptest stays out of the runner.
+
+use proptest::prelude::*;
+use proptest::string::string_regex;
+use proptest::test_runner::{TestCaseError, TestRunner};
+
+use crate::solution::Solution;
+
+/// Arbitrary text or text matching `pattern`, a regex over the characters of the input format.
+/// Arbitrary text rarely gets past the first check of a parser, text that looks like an input
+/// does.
+pub fn inputs(pattern: &str) -> BoxedStrategy<String> {
+    let looks_like_input =
+        string_regex(pattern).unwrap_or_else(|e| panic!("Invalid pattern {}: {}", pattern, e));
+
+    prop_oneof![any::<String>(), looks_like_input].boxed()
+}
+
+/// Panics with the smallest input found that makes `S::parse` panic instead of failing.
+pub fn check_parse_no_panic<S: Solution>(pattern: &str) {
+    let result = TestRunner::default().run(&inputs(pattern), |input| {
+        let _ = S::parse(&input);
+        Ok::<(), TestCaseError>(())
+    });
+
+    if let Err(error) = result {
+        panic!("Day {} parser panicked: {}", S::DAY, error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::Result;
+
+    /// Parses a number and indexes with it, which panics for anything but 0.
+    struct Indexing;
+
+    impl Solution for Indexing {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Indexing";
+
+        type Input = u8;
+
+        fn parse(input: &str) -> Result<u8> {
+            let index = input.parse::<usize>().map_err(|e| e.to_string())?;
+            Ok([0][index])
+        }
+
+        fn part1(input: &u8) -> Result<Answer> {
+            Ok(u64::from(*input).into())
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Day 0 parser panicked")]
+    fn test_check_parse_no_panic() {
+        check_parse_no_panic::<Indexing>("[0-9]{1,3}");
+    }
+}
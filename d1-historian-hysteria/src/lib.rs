@@ -0,0 +1,85 @@
+//! Day 1: pairs up two lists of location IDs and compares them.
+
+use aoc24::answer::{self, Answer};
+use aoc24::parse;
+use aoc24::registry::Registry;
+use aoc24::solution::Solution;
+use aoc24::{Error, Result};
+
+pub struct HistorianHysteria;
+
+/// Both location lists, sorted once they've been through [`Solution::parse`].
+pub struct Input {
+    pub left: Vec<i64>,
+    pub right: Vec<i64>,
+}
+
+impl Solution for HistorianHysteria {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        let mut input = parse_input(input)?;
+
+        input.left.sort();
+        input.right.sort();
+
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        Ok(calc_distance(&input.left, &input.right)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        Ok(calc_similarity_score(&input.left, &input.right)?.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<HistorianHysteria>(env!("CARGO_MANIFEST_DIR"));
+}
+
+/// Sum of every left ID times the number of times it appears in the right list.
+pub fn calc_similarity_score(a: &[i64], b: &[i64]) -> Result<i64> {
+    let mut scores = Vec::new();
+
+    for x in a {
+        let occurrences = b.iter().filter(|&y| x == y).count() as i64;
+        let score = x
+            .checked_mul(occurrences)
+            .ok_or_else(|| Error::new(format!("overflow scoring {} x {}", x, occurrences)))?;
+        scores.push(score);
+    }
+
+    answer::checked_sum(scores)
+}
+
+/// Sum of the distances between the IDs at the same position of two sorted lists.
+pub fn calc_distance(a: &[i64], b: &[i64]) -> Result<u64> {
+    let mut distances = Vec::new();
+
+    for i in 0..a.len() {
+        let distance = a[i].abs_diff(b[i]);
+        distances.push(distance);
+    }
+
+    answer::checked_sum(distances)
+}
+
+fn parse_input(str: &str) -> parse::Result<Input> {
+    let pairs = parse::lines(str, |line| {
+        let [left, right]: [i64; 2] = line
+            .parse_words()?
+            .try_into()
+            .map_err(|_| line.error("expected two location IDs"))?;
+
+        Ok((left, right))
+    })?;
+
+    let (left, right) = pairs.into_iter().unzip();
+
+    Ok(Input { left, right })
+}
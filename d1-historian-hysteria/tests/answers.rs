@@ -0,0 +1 @@
+aoc24::answer_tests!(d1_historian_hysteria::HistorianHysteria);
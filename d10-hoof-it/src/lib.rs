@@ -0,0 +1,142 @@
+//! Day 10: scores the hiking trails that climb from height 0 to 9 on a topographic map.
+
+use std::collections::HashSet;
+
+use aoc24::answer::Answer;
+use aoc24::grid::Grid;
+use aoc24::memo::Memo;
+use aoc24::registry::Registry;
+use aoc24::solution::Solution;
+use aoc24::{Error, Result};
+
+pub struct HoofIt;
+
+/// Topographic map with a height digit per cell.
+pub struct TrailMap {
+    data: Grid,
+    distinct_mode: bool,
+}
+
+impl TrailMap {
+    pub fn new(data: Grid) -> Self {
+        TrailMap {
+            data,
+            distinct_mode: false,
+        }
+    }
+
+    /// Rates trailheads by their number of distinct trails instead of the peaks they reach.
+    pub fn distinct(mut self) -> Self {
+        self.distinct_mode = true;
+        self
+    }
+
+    /// Sum of the scores, or ratings in distinct mode, of every trailhead.
+    pub fn calc_score(&self) -> usize {
+        let trailheads = self.find_trailheads();
+
+        if self.distinct_mode {
+            let mut memo = Memo::new();
+
+            return trailheads
+                .into_iter()
+                .map(|position| memo.call(position, |memo, p| self.count_trails(memo, p)))
+                .sum();
+        }
+
+        let mut memo = Memo::new();
+
+        trailheads
+            .into_iter()
+            .map(|position| {
+                memo.call(position, |memo, p| self.reachable_peaks(memo, p))
+                    .len()
+            })
+            .sum()
+    }
+
+    /// Number of distinct hiking trails from the position to any peak.
+    fn count_trails(
+        &self,
+        memo: &mut Memo<(usize, usize), usize>,
+        (x, y): (usize, usize),
+    ) -> usize {
+        if self.get_height_at(x, y) == 9 {
+            return 1;
+        }
+
+        self.traversable_neighbors(x, y)
+            .into_iter()
+            .map(|position| memo.call(position, |memo, p| self.count_trails(memo, p)))
+            .sum()
+    }
+
+    /// Peaks reachable from the position.
+    fn reachable_peaks(
+        &self,
+        memo: &mut Memo<(usize, usize), HashSet<(usize, usize)>>,
+        (x, y): (usize, usize),
+    ) -> HashSet<(usize, usize)> {
+        if self.get_height_at(x, y) == 9 {
+            return HashSet::from([(x, y)]);
+        }
+
+        self.traversable_neighbors(x, y)
+            .into_iter()
+            .flat_map(|position| memo.call(position, |memo, p| self.reachable_peaks(memo, p)))
+            .collect()
+    }
+
+    fn traversable_neighbors(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        let base = self.get_height_at(x, y);
+
+        self.data
+            .neighbors_iter(x, y)
+            .filter(|(nx, ny)| self.get_height_at(*nx, *ny) as i32 - base as i32 == 1)
+            .collect()
+    }
+
+    fn get_height_at(&self, x: usize, y: usize) -> usize {
+        self.data.get(x, y).to_digit(10).unwrap() as usize
+    }
+
+    fn find_trailheads(&self) -> Vec<(usize, usize)> {
+        self.data
+            .coordinates_iter()
+            .filter(|(x, y)| self.data.get(*x, *y) == '0')
+            .collect()
+    }
+}
+
+impl Solution for HoofIt {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid> {
+        let grid = input.parse::<Grid>()?;
+
+        for (x, y) in grid.coordinates_iter() {
+            if !grid.get(x, y).is_ascii_digit() {
+                return Err(
+                    Error::new(format!("invalid height `{}`", grid.get(x, y))).at(y + 1, x + 1)
+                );
+            }
+        }
+
+        Ok(grid)
+    }
+
+    fn part1(grid: &Grid) -> Result<Answer> {
+        Ok(TrailMap::new(grid.clone()).calc_score().into())
+    }
+
+    fn part2(grid: &Grid) -> Result<Answer> {
+        Ok(TrailMap::new(grid.clone()).distinct().calc_score().into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<HoofIt>(env!("CARGO_MANIFEST_DIR"));
+}
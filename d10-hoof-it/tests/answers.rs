@@ -0,0 +1 @@
+aoc24::answer_tests!(d10_hoof_it::HoofIt);
@@ -0,0 +1,138 @@
+//! Day 11: counts the stones that keep splitting every time you blink.
+
+use std::fmt;
+
+use aoc24::answer::Answer;
+use aoc24::math;
+use aoc24::memo::Memo;
+use aoc24::parse::{self, ParseError, Span};
+use aoc24::registry::Registry;
+use aoc24::solution::Solution;
+use aoc24::Result;
+
+pub struct PlutonianPebbles;
+
+/// Engraved numbers on the stones, in the order they're lined up.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MagicStones {
+    stones: Vec<usize>,
+}
+
+impl MagicStones {
+    const MULTIPLIER: usize = 2024;
+
+    /// Number of stones in the line after blinking `blinks` times.
+    pub fn count_after(&self, blinks: usize) -> usize {
+        let mut memo = Memo::new();
+
+        let count = self
+            .stones
+            .iter()
+            .map(|&stone| memo.call((stone, blinks), Self::count_stones))
+            .sum();
+
+        aoc24::debug!(
+            "{} blinks: {} cached, {} hits",
+            blinks,
+            memo.len(),
+            memo.hits()
+        );
+
+        count
+    }
+
+    /// Number of stones a single stone turns into after the given number of blinks.
+    fn count_stones(
+        memo: &mut Memo<(usize, usize), usize>,
+        (stone, blinks): (usize, usize),
+    ) -> usize {
+        if blinks == 0 {
+            return 1;
+        }
+
+        if stone == 0 {
+            return memo.call((1, blinks - 1), Self::count_stones);
+        }
+
+        if let Some((left, right)) = Self::split_stone(stone) {
+            return memo.call((left, blinks - 1), Self::count_stones)
+                + memo.call((right, blinks - 1), Self::count_stones);
+        }
+
+        memo.call((stone * Self::MULTIPLIER, blinks - 1), Self::count_stones)
+    }
+
+    fn split_stone(stone: usize) -> Option<(usize, usize)> {
+        let digits = math::digits(stone as u64);
+
+        if !digits.is_multiple_of(2) {
+            return None;
+        }
+
+        let (left, right) = math::split_digits(stone as u64, digits / 2);
+
+        Some((left as usize, right as usize))
+    }
+}
+
+impl fmt::Display for MagicStones {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let stones = self.stones.iter().map(usize::to_string).collect::<Vec<_>>();
+        write!(f, "{}", stones.join(" "))
+    }
+}
+
+impl TryFrom<Span<'_>> for MagicStones {
+    type Error = ParseError;
+
+    fn try_from(s: Span) -> parse::Result<Self> {
+        Ok(MagicStones {
+            stones: s.parse_words()?,
+        })
+    }
+}
+
+impl Solution for PlutonianPebbles {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    type Input = MagicStones;
+
+    fn parse(input: &str) -> Result<MagicStones> {
+        Ok(MagicStones::try_from(Span::new(input))?)
+    }
+
+    fn part1(magic_stones: &MagicStones) -> Result<Answer> {
+        Ok(magic_stones.count_after(25).into())
+    }
+
+    fn part2(magic_stones: &MagicStones) -> Result<Answer> {
+        Ok(magic_stones.count_after(75).into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<PlutonianPebbles>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc24::testing;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_magic_stones_round_trip(stones in prop::collection::vec(any::<usize>(), 1..20)) {
+            let magic_stones = MagicStones { stones };
+            let parsed = MagicStones::try_from(Span::new(&magic_stones.to_string())).unwrap();
+
+            prop_assert_eq!(parsed, magic_stones);
+        }
+    }
+
+    #[test]
+    fn test_parse_no_panic() {
+        testing::check_parse_no_panic::<PlutonianPebbles>("[0-9 \n-]{0,30}");
+    }
+}
@@ -0,0 +1 @@
+aoc24::answer_tests!(d11_plutonian_pebbles::PlutonianPebbles);
@@ -0,0 +1,352 @@
+//! Day 14: moves security robots around a wrapping bathroom floor and waits for a Christmas tree.
+
+use std::fmt;
+
+use aoc24::answer::Answer;
+use aoc24::frames::FrameRecorder;
+use aoc24::grid::Grid;
+use aoc24::math;
+use aoc24::parallel;
+use aoc24::parse::{self, ParseError, Span};
+use aoc24::progress::Progress;
+use aoc24::registry::Registry;
+use aoc24::solution::{Part, Solution};
+use aoc24::Result;
+
+pub struct RestroomRedoubt;
+
+/// Position and velocity in tiles per second, as in `p=0,4 v=3,-3`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Robot {
+    pub x: i32,
+    pub y: i32,
+    pub vx: i32,
+    pub vy: i32,
+}
+
+/// Every robot on a floor of `width` by `height` tiles.
+#[derive(Clone)]
+pub struct Robots {
+    value: Vec<Robot>,
+    width: i32,
+    height: i32,
+}
+
+impl Robots {
+    pub fn new(value: Vec<Robot>, width: i32, height: i32) -> Self {
+        Robots {
+            value,
+            width,
+            height,
+        }
+    }
+
+    pub fn robots(&self) -> &[Robot] {
+        &self.value
+    }
+
+    /// Moves every robot one second at a time.
+    pub fn simulate(&mut self, seconds: i32) {
+        for i in 0..seconds {
+            aoc24::trace!("{}s", i);
+            self.simulate_second();
+        }
+    }
+
+    /// Second at which the robots bunch up into a picture, if they ever do.
+    pub fn find_tree(&self) -> Option<i32> {
+        let seconds = self
+            .tightest_second()
+            .filter(|&seconds| self.after(seconds).has_cluster())
+            .or_else(|| self.search_tree())?;
+
+        aoc24::debug!("{}:\n{}", seconds, self.after(seconds));
+
+        Some(seconds)
+    }
+
+    /// Second at which the robots are closest together along both axes. Every x repeats after
+    /// width seconds and every y after height seconds, so each axis is only checked over its own
+    /// period and the two seconds are combined with the Chinese Remainder Theorem.
+    fn tightest_second(&self) -> Option<i32> {
+        let tightest = |size: i32, axis: fn(&Robot) -> (i32, i32)| {
+            (0..size)
+                .min_by_key(|&seconds| {
+                    spread(self.value.iter().map(|robot| {
+                        let (position, velocity) = axis(robot);
+                        (position + velocity * seconds).rem_euclid(size)
+                    }))
+                })
+                .unwrap_or(0)
+        };
+
+        let x = tightest(self.width, |robot| (robot.x, robot.vx));
+        let y = tightest(self.height, |robot| (robot.y, robot.vy));
+
+        let (seconds, _) = math::crt(&[
+            (x.into(), self.width.into()),
+            (y.into(), self.height.into()),
+        ])?;
+
+        i32::try_from(seconds).ok()
+    }
+
+    /// First second with a cluster, checking them all.
+    fn search_tree(&self) -> Option<i32> {
+        const CHUNK_SIZE: usize = 256;
+
+        let period = math::lcm(self.width.into(), self.height.into()) as usize;
+        let progress = Progress::new("Searching for the tree", period as u64);
+
+        parallel::find_first(0..period, CHUNK_SIZE, |i| {
+            progress.inc(1);
+            self.after(i as i32).has_cluster()
+        })
+        .map(|seconds| seconds as i32)
+    }
+
+    /// Positions after `seconds`, computed directly so any second can be checked on its own.
+    pub fn after(&self, seconds: i32) -> Robots {
+        let mut robots = self.clone();
+
+        for robot in robots.value.iter_mut() {
+            robot.x = (robot.x + robot.vx * seconds).rem_euclid(self.width);
+            robot.y = (robot.y + robot.vy * seconds).rem_euclid(self.height);
+        }
+
+        robots
+    }
+
+    fn has_cluster(&self) -> bool {
+        const SCAN_WIDTH: i32 = 4;
+        const SCAN_HEIGHT: i32 = 4;
+
+        for x in 0..self.width {
+            for y in 0..self.height {
+                let mut is_cluster = true;
+
+                'scan: for dx in 0..SCAN_WIDTH {
+                    for dy in 0..SCAN_HEIGHT {
+                        let occupied = self
+                            .value
+                            .iter()
+                            .any(|robot| robot.x == x + dx && robot.y == y + dy);
+
+                        if !occupied {
+                            is_cluster = false;
+                            break 'scan;
+                        }
+                    }
+                }
+
+                if is_cluster {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    fn simulate_second(&mut self) {
+        for robot in self.value.iter_mut() {
+            robot.x = (robot.x + robot.vx) % self.width;
+            if robot.x < 0 {
+                robot.x += self.width;
+            }
+
+            robot.y = (robot.y + robot.vy) % self.height;
+            if robot.y < 0 {
+                robot.y += self.height;
+            }
+        }
+    }
+
+    /// Grid with the number of robots on each occupied tile.
+    pub fn to_grid(&self) -> Grid {
+        let mut grid = Grid::with_default_char(self.width as usize, self.height as usize, '.');
+
+        for robot in self.value.iter() {
+            let curr = grid.get(robot.x as usize, robot.y as usize).to_digit(10);
+
+            if let Some(digit) = curr {
+                grid.set(
+                    robot.x as usize,
+                    robot.y as usize,
+                    (digit + 1).to_string().chars().next().unwrap(),
+                );
+            } else {
+                grid.set(robot.x as usize, robot.y as usize, '1');
+            }
+        }
+
+        grid
+    }
+
+    /// Product of the number of robots in each quadrant, ignoring the middle row and column.
+    pub fn calculate_safety_factor(&self) -> usize {
+        let cx = self.width / 2;
+        let cy = self.height / 2;
+
+        let q1 = self
+            .value
+            .iter()
+            .filter(|robot| robot.x < cx && robot.y < cy)
+            .count();
+
+        let q2 = self
+            .value
+            .iter()
+            .filter(|robot| robot.x > cx && robot.y < cy)
+            .count();
+
+        let q3 = self
+            .value
+            .iter()
+            .filter(|robot| robot.x < cx && robot.y > cy)
+            .count();
+
+        let q4 = self
+            .value
+            .iter()
+            .filter(|robot| robot.x > cx && robot.y > cy)
+            .count();
+
+        q1 * q2 * q3 * q4
+    }
+}
+
+/// Variance of the positions times their count squared, which orders like the variance.
+fn spread(positions: impl Iterator<Item = i32>) -> i64 {
+    let (n, sum, sum_of_squares) = positions.fold((0i64, 0i64, 0i64), |(n, sum, squares), p| {
+        let p = i64::from(p);
+        (n + 1, sum + p, squares + p * p)
+    });
+
+    n * sum_of_squares - sum * sum
+}
+
+impl fmt::Display for Robots {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_grid())
+    }
+}
+
+impl fmt::Display for Robot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "p={},{} v={},{}", self.x, self.y, self.vx, self.vy)
+    }
+}
+
+impl TryFrom<Span<'_>> for Robot {
+    type Error = ParseError;
+
+    fn try_from(line: Span) -> parse::Result<Self> {
+        let (position, velocity) = line.trim().split_once(" ")?;
+        let [x, y] = position.strip_prefix("p=")?.array(",")?;
+        let [vx, vy] = velocity.trim().strip_prefix("v=")?.array(",")?;
+
+        Ok(Robot { x, y, vx, vy })
+    }
+}
+
+impl RestroomRedoubt {
+    const SECONDS: i32 = 100;
+    const WIDTH: i32 = 101;
+    const HEIGHT: i32 = 103;
+    const EXAMPLE_WIDTH: i32 = 11;
+    const EXAMPLE_HEIGHT: i32 = 7;
+    /// Seconds recorded before the tree shows up.
+    const TREE_LEAD_IN: i32 = 30;
+}
+
+impl Solution for RestroomRedoubt {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    type Input = Robots;
+
+    fn parse(input: &str) -> Result<Robots> {
+        let robots = parse::lines(input, Robot::try_from)?;
+
+        // The example uses a smaller space than the real input, which isn't part of the input
+        let is_example = robots
+            .iter()
+            .all(|robot| robot.x < Self::EXAMPLE_WIDTH && robot.y < Self::EXAMPLE_HEIGHT);
+
+        if is_example {
+            Ok(Robots::new(
+                robots,
+                Self::EXAMPLE_WIDTH,
+                Self::EXAMPLE_HEIGHT,
+            ))
+        } else {
+            Ok(Robots::new(robots, Self::WIDTH, Self::HEIGHT))
+        }
+    }
+
+    fn part1(robots: &Robots) -> Result<Answer> {
+        let mut robots = robots.clone();
+        robots.simulate(Self::SECONDS);
+        Ok(robots.calculate_safety_factor().into())
+    }
+
+    fn part2(robots: &Robots) -> Result<Answer> {
+        match robots.find_tree() {
+            Some(seconds) => Ok(seconds.into()),
+            None => Ok(Answer::Unsolved),
+        }
+    }
+
+    fn record(robots: &Robots, part: Part, recorder: &mut FrameRecorder) -> Result<()> {
+        let seconds = match part {
+            Part::One => 0..=Self::SECONDS,
+            Part::Two => {
+                let tree = robots.find_tree().ok_or("No second shows the tree")?;
+                (tree - Self::TREE_LEAD_IN).max(0)..=tree
+            }
+        };
+
+        for second in seconds {
+            recorder.snapshot(&robots.after(second).to_grid());
+        }
+
+        Ok(())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<RestroomRedoubt>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc24::testing;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_robot_try_from() {
+        let input = "p=0,4 v=3,-3";
+        let robot = Robot::try_from(Span::new(input)).unwrap();
+        assert_eq!(robot.x, 0);
+        assert_eq!(robot.y, 4);
+        assert_eq!(robot.vx, 3);
+        assert_eq!(robot.vy, -3);
+    }
+
+    proptest! {
+        #[test]
+        fn test_robot_round_trip(x: i32, y: i32, vx: i32, vy: i32) {
+            let robot = Robot { x, y, vx, vy };
+            let parsed = Robot::try_from(Span::new(&robot.to_string())).unwrap();
+
+            prop_assert_eq!(parsed, robot);
+        }
+    }
+
+    #[test]
+    fn test_parse_no_panic() {
+        testing::check_parse_no_panic::<RestroomRedoubt>("[pv=0-9, \n-]{0,40}");
+    }
+}
@@ -0,0 +1 @@
+aoc24::answer_tests!(d14_restroom_redoubt::RestroomRedoubt);
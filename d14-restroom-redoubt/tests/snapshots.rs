@@ -0,0 +1,8 @@
+use d14_restroom_redoubt::RestroomRedoubt;
+
+#[test]
+fn robots() {
+    let robots = aoc24::snapshot_example!(RestroomRedoubt);
+
+    aoc24::assert_snapshot!("robots", format!("{}\n{}", robots, robots.after(100)));
+}
@@ -0,0 +1,104 @@
+//! Day 2: checks reactor reports for safely increasing or decreasing levels.
+
+use std::cmp;
+
+use aoc24::answer::Answer;
+use aoc24::parse;
+use aoc24::registry::Registry;
+use aoc24::solution::Solution;
+use aoc24::Result;
+
+pub struct RedNosedReports;
+
+enum ReportTrend {
+    Increasing,
+    Decreasing,
+    None,
+}
+
+impl Solution for RedNosedReports {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>> {
+        Ok(parse_reports(input)?)
+    }
+
+    fn part1(reports: &Vec<Vec<i32>>) -> Result<Answer> {
+        Ok(count_safe_reports(reports, is_strictly_safe_report).into())
+    }
+
+    fn part2(reports: &Vec<Vec<i32>>) -> Result<Answer> {
+        Ok(count_safe_reports(reports, is_safe_report).into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<RedNosedReports>(env!("CARGO_MANIFEST_DIR"));
+}
+
+/// Number of reports that pass `is_safe`.
+pub fn count_safe_reports(reports: &[Vec<i32>], is_safe: fn(&[i32]) -> bool) -> i32 {
+    reports.iter().filter(|&report| is_safe(report)).count() as i32
+}
+
+/// Whether the report is safe once at most one level is removed from it.
+pub fn is_safe_report(report: &[i32]) -> bool {
+    if is_strictly_safe_report(report) {
+        return true;
+    }
+
+    for i in 0..report.len() {
+        let mut report_copy = report.to_vec();
+        report_copy.remove(i);
+
+        if is_strictly_safe_report(&report_copy) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Whether levels all increase or all decrease, by 1 to 3 at a time.
+pub fn is_strictly_safe_report(report: &[i32]) -> bool {
+    let mut trend = ReportTrend::None;
+
+    for window in report.windows(2) {
+        let a = window[0];
+        let b = window[1];
+        let diff = (a - b).abs();
+
+        if !(1..=3).contains(&diff) {
+            return false;
+        }
+
+        match trend {
+            ReportTrend::None => match a.cmp(&b) {
+                cmp::Ordering::Less => trend = ReportTrend::Increasing,
+                cmp::Ordering::Greater => trend = ReportTrend::Decreasing,
+                cmp::Ordering::Equal => {
+                    return false;
+                }
+            },
+            ReportTrend::Increasing => {
+                if a >= b {
+                    return false;
+                }
+            }
+            ReportTrend::Decreasing => {
+                if a <= b {
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
+
+fn parse_reports(str: &str) -> parse::Result<Vec<Vec<i32>>> {
+    parse::lines(str, |line| line.parse_words())
+}
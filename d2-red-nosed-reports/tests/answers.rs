@@ -0,0 +1 @@
+aoc24::answer_tests!(d2_red_nosed_reports::RedNosedReports);
@@ -0,0 +1,102 @@
+//! Day 22: predicts the pseudorandom secret numbers of the monkey market's buyers.
+
+use aoc24::answer::Answer;
+use aoc24::parallel;
+use aoc24::parse::Span;
+use aoc24::registry::Registry;
+use aoc24::solution::Solution;
+use aoc24::Result;
+
+pub struct MonkeyMarket;
+
+impl Solution for MonkeyMarket {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Monkey Market";
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        Ok(Span::new(input).parse_words()?)
+    }
+
+    fn part1(secrets: &Vec<usize>) -> Result<Answer> {
+        Ok(
+            parallel::map(secrets, |&x| calculate_nth_secret_number(x, 2000))
+                .into_iter()
+                .sum::<usize>()
+                .into(),
+        )
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<MonkeyMarket>(env!("CARGO_MANIFEST_DIR"));
+}
+
+/// Secret number after `n` rounds of evolving `start`.
+pub fn calculate_nth_secret_number(start: usize, n: usize) -> usize {
+    let mut secret = start;
+
+    for _ in 0..n {
+        secret = generate_next_secret_number(secret);
+    }
+
+    secret
+}
+
+/// One round of the multiply, divide and multiply steps, each mixed and pruned.
+pub fn generate_next_secret_number(secret: usize) -> usize {
+    let mut salt = secret * 64;
+    let mut secret = prune_secret(mix_secret(secret, salt));
+    salt = secret / 32;
+    secret = prune_secret(mix_secret(secret, salt));
+    salt = secret * 2048;
+    prune_secret(mix_secret(secret, salt))
+}
+
+fn mix_secret(secret: usize, salt: usize) -> usize {
+    secret ^ salt
+}
+
+fn prune_secret(secret: usize) -> usize {
+    secret % 16777216
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mix_secret() {
+        let secret = 42;
+        let salt = 15;
+        let result = mix_secret(secret, salt);
+        let expected = 37;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_prune_secret() {
+        let secret = 100000000;
+        let result = prune_secret(secret);
+        let expected = 16113920;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_generate_next_secret_number() {
+        let secret = 123;
+        let result = generate_next_secret_number(secret);
+        let expected = 15887950;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_calculate_nth_secret_number() {
+        let secret = 1;
+        let n = 2000;
+        let result = calculate_nth_secret_number(secret, n);
+        let expected = 8685429;
+        assert_eq!(result, expected);
+    }
+}
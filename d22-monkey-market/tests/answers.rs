@@ -0,0 +1 @@
+aoc24::answer_tests!(d22_monkey_market::MonkeyMarket);
@@ -0,0 +1,105 @@
+//! Day 3: runs the `mul`, `do` and `don't` instructions buried in corrupted memory.
+
+use aoc24::answer::Answer;
+use aoc24::parse::Span;
+use aoc24::registry::Registry;
+use aoc24::solution::Solution;
+use aoc24::{Error, Result};
+use regex::Regex;
+
+pub struct MullItOver;
+
+/// Executes instructions one at a time and keeps the running sum of the enabled `mul`s.
+pub struct Processor {
+    accumulator: i32,
+    is_mul_enabled: bool,
+}
+
+impl Processor {
+    const INSTRUCTION_MUL: &'static str = "mul";
+    const INSTRUCTION_DO: &'static str = "do";
+    const INSTRUCTION_DONT: &'static str = "don't";
+
+    pub fn new() -> Processor {
+        Processor {
+            accumulator: 0,
+            is_mul_enabled: true,
+        }
+    }
+
+    /// Runs one instruction such as `mul(2,4)` or `don't()`.
+    pub fn execute_instruction(&mut self, instruction: &str) -> Result<()> {
+        let invalid = || Error::new(format!("Invalid instruction: {}", instruction));
+
+        let (function_name, rest) = instruction.split_once("(").ok_or_else(invalid)?;
+        let parameters = rest.strip_suffix(")").ok_or_else(invalid)?;
+
+        match function_name {
+            Self::INSTRUCTION_MUL => self.execute_mul(parameters)?,
+            Self::INSTRUCTION_DO => self.is_mul_enabled = true,
+            Self::INSTRUCTION_DONT => self.is_mul_enabled = false,
+            _ => return Err(invalid()),
+        }
+
+        Ok(())
+    }
+
+    pub fn accumulator(&self) -> i32 {
+        self.accumulator
+    }
+
+    fn execute_mul(&mut self, parameters: &str) -> Result<()> {
+        if !self.is_mul_enabled {
+            return Ok(());
+        }
+
+        let [lhs, rhs] = Span::new(parameters)
+            .array::<i32, 2>(",")
+            .map_err(|e| Error::new(format!("mul({}): {}", parameters, e.message())))?;
+
+        self.accumulator += lhs * rhs;
+
+        Ok(())
+    }
+}
+
+impl Default for Processor {
+    fn default() -> Self {
+        Processor::new()
+    }
+}
+
+impl Solution for MullItOver {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(contents: &String) -> Result<Answer> {
+        Ok(run_program(contents, r"mul\(\d{1,3},\d{1,3}\)")?.into())
+    }
+
+    fn part2(contents: &String) -> Result<Answer> {
+        Ok(run_program(contents, r"(don't\(\))|(do\(\))|(mul\(\d{1,3},\d{1,3}\))")?.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<MullItOver>(env!("CARGO_MANIFEST_DIR"));
+}
+
+/// Runs every instruction in `contents` that matches `pattern` and returns the accumulator.
+pub fn run_program(contents: &str, pattern: &str) -> Result<i32> {
+    let regex = Regex::new(pattern).map_err(|e| Error::new(e.to_string()))?;
+    let mut processor = Processor::new();
+
+    for instruction in regex.find_iter(contents) {
+        processor.execute_instruction(instruction.as_str())?;
+    }
+
+    Ok(processor.accumulator())
+}
@@ -0,0 +1 @@
+aoc24::answer_tests!(d3_mull_it_over::MullItOver);
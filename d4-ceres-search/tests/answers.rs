@@ -0,0 +1 @@
+aoc24::answer_tests!(d4_ceres_search::CeresSearch);
@@ -0,0 +1,256 @@
+//! Day 5: checks safety manual updates against the page ordering rules and fixes the bad ones.
+
+use std::fmt;
+
+use aoc24::answer::Answer;
+use aoc24::parse::{self, ParseError, Span};
+use aoc24::registry::Registry;
+use aoc24::solution::Solution;
+use aoc24::Result;
+
+pub struct PrintQueue;
+
+/// Page that has to be printed before another one, as `[before, after]`.
+pub type PrintRule = [i32; 2];
+
+/// Every ordering rule from the first section of the input.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PrintRules {
+    rules: Vec<PrintRule>,
+}
+
+impl PrintRules {
+    /// Whether every page of the update is in an order the rules allow.
+    pub fn verify_update(&self, update: Vec<i32>) -> bool {
+        for current_page_index in 0..update.len() {
+            let page = update[current_page_index];
+            let previous_page_rules = self.previous_page_rules(page);
+            let next_page_rules = self.next_page_rules(page);
+
+            for &previous_page in &update[..current_page_index] {
+                let should_be_before_current_page = previous_page_rules
+                    .iter()
+                    .any(|rule| rule[0] == previous_page);
+
+                if !should_be_before_current_page {
+                    return false;
+                }
+            }
+
+            for &next_page in &update[(current_page_index + 1)..] {
+                let should_be_after_current_page =
+                    next_page_rules.iter().any(|rule| rule[1] == next_page);
+
+                if !should_be_after_current_page {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Reorders the pages of an update until they follow the rules.
+    pub fn sort(&self, update: Vec<i32>) -> Vec<i32> {
+        let mut sorted_update = update.clone();
+
+        for current_page_index in 0..update.len() {
+            let current_page = update[current_page_index];
+            let previous_page_rules = self.previous_page_rules(current_page);
+            let next_page_rules = self.next_page_rules(current_page);
+
+            for (previous_page_index, &previous_page) in
+                update.iter().enumerate().take(current_page_index)
+            {
+                let should_be_before_current_page = previous_page_rules
+                    .iter()
+                    .any(|rule| rule[0] == previous_page);
+
+                if !should_be_before_current_page {
+                    sorted_update.swap(previous_page_index, current_page_index);
+                    return self.sort(sorted_update);
+                }
+            }
+
+            for (next_page_index, &next_page) in
+                update.iter().enumerate().skip(current_page_index + 1)
+            {
+                let should_be_after_current_page =
+                    next_page_rules.iter().any(|rule| rule[1] == next_page);
+
+                if !should_be_after_current_page {
+                    sorted_update.swap(next_page_index, current_page_index);
+                    return self.sort(sorted_update);
+                }
+            }
+        }
+
+        sorted_update
+    }
+
+    fn previous_page_rules(&self, page: i32) -> Vec<&PrintRule> {
+        self.rules.iter().filter(|rule| rule[1] == page).collect()
+    }
+
+    fn next_page_rules(&self, page: i32) -> Vec<&PrintRule> {
+        self.rules.iter().filter(|rule| rule[0] == page).collect()
+    }
+}
+
+/// Renders the rules as they appear in the input, one `before|after` per line.
+impl fmt::Display for PrintRules {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for [before, after] in &self.rules {
+            writeln!(f, "{}|{}", before, after)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<Span<'_>> for PrintRules {
+    type Error = ParseError;
+
+    fn try_from(rules: Span) -> parse::Result<Self> {
+        let rules = rules.map_lines(|rule| rule.array("|"))?;
+
+        Ok(PrintRules { rules })
+    }
+}
+
+/// Page numbers of every update from the second section of the input.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PrintUpdates {
+    updates: Vec<Vec<i32>>,
+}
+
+impl PrintUpdates {
+    pub fn updates(&self) -> &[Vec<i32>] {
+        &self.updates
+    }
+}
+
+/// Renders the updates as they appear in the input, comma separated pages per line.
+impl fmt::Display for PrintUpdates {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for update in &self.updates {
+            let pages = update.iter().map(i32::to_string).collect::<Vec<_>>();
+            writeln!(f, "{}", pages.join(","))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<Span<'_>> for PrintUpdates {
+    type Error = ParseError;
+
+    fn try_from(updates: Span) -> parse::Result<Self> {
+        let updates = updates.map_lines(|update| update.list(","))?;
+
+        Ok(PrintUpdates { updates })
+    }
+}
+
+impl Solution for PrintQueue {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = (PrintRules, PrintUpdates);
+
+    fn parse(input: &str) -> Result<(PrintRules, PrintUpdates)> {
+        let [rules, updates] = Span::new(input).sections_array()?;
+
+        Ok((
+            PrintRules::try_from(rules)?,
+            PrintUpdates::try_from(updates)?,
+        ))
+    }
+
+    fn part1((print_rules, print_updates): &(PrintRules, PrintUpdates)) -> Result<Answer> {
+        Ok(part1(print_rules, print_updates).into())
+    }
+
+    fn part2((print_rules, print_updates): &(PrintRules, PrintUpdates)) -> Result<Answer> {
+        Ok(part2(print_rules, print_updates).into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<PrintQueue>(env!("CARGO_MANIFEST_DIR"));
+}
+
+/// Sum of the middle pages of the updates that are already in order.
+pub fn part1(print_rules: &PrintRules, print_updates: &PrintUpdates) -> i32 {
+    let mut acc = 0;
+
+    for update in &print_updates.updates {
+        let is_valid = print_rules.verify_update(update.to_vec());
+
+        if is_valid {
+            acc += update[update.len() / 2];
+        }
+    }
+
+    acc
+}
+
+/// Sum of the middle pages of the out of order updates, once they have been sorted.
+pub fn part2(print_rules: &PrintRules, print_updates: &PrintUpdates) -> i32 {
+    let invalid_updates = print_updates
+        .updates
+        .iter()
+        .filter(|update| !print_rules.verify_update(update.to_vec()))
+        .collect::<Vec<&Vec<i32>>>();
+
+    let fixed_updates = invalid_updates
+        .iter()
+        .map(|update| print_rules.sort(update.to_vec()))
+        .collect::<Vec<Vec<i32>>>();
+
+    let mut acc = 0;
+
+    for update in &fixed_updates {
+        acc += update[update.len() / 2];
+    }
+
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc24::testing;
+    use proptest::prelude::*;
+
+    fn print_queue() -> impl Strategy<Value = (PrintRules, PrintUpdates)> {
+        let rules = prop::collection::vec(any::<PrintRule>(), 1..20);
+        let updates = prop::collection::vec(prop::collection::vec(any::<i32>(), 1..10), 1..10);
+
+        (rules, updates)
+            .prop_map(|(rules, updates)| (PrintRules { rules }, PrintUpdates { updates }))
+    }
+
+    proptest! {
+        #[test]
+        fn test_print_queue_round_trip((rules, updates) in print_queue()) {
+            let input = format!("{}\n{}", rules, updates);
+            let (parsed_rules, parsed_updates) = PrintQueue::parse(&input).unwrap();
+
+            prop_assert_eq!(parsed_rules, rules);
+            prop_assert_eq!(parsed_updates, updates);
+        }
+
+        #[test]
+        fn test_print_rules_text_round_trip(input in "(-?[1-9][0-9]{0,4}\\|-?[1-9][0-9]{0,4}\n){1,10}") {
+            let rules = PrintRules::try_from(Span::new(&input)).unwrap();
+
+            prop_assert_eq!(rules.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_parse_no_panic() {
+        testing::check_parse_no_panic::<PrintQueue>("[0-9|,\n-]{0,40}");
+    }
+}
@@ -0,0 +1 @@
+aoc24::answer_tests!(d5_print_queue::PrintQueue);
@@ -0,0 +1,281 @@
+//! Day 6: walks the guard's patrol and looks for obstacles that trap it in a loop.
+
+use aoc24::answer::Answer;
+use aoc24::frames::FrameRecorder;
+use aoc24::grid::Grid;
+use aoc24::parallel;
+use aoc24::progress::Progress;
+use aoc24::registry::Registry;
+use aoc24::solution::{Part, Solution};
+use aoc24::Result;
+
+pub struct GuardGallivant;
+
+/// Moves the guard across the lab, marking its path on the grid as it goes.
+pub struct PatrolSimulator<'a> {
+    grid: Grid,
+    guard_position: (usize, usize),
+    frames: Option<&'a mut FrameRecorder>,
+}
+
+/// How a patrol ended.
+pub enum Simulation {
+    /// The guard left the lab after visiting this many cells.
+    DistinctPositions(usize),
+    /// The guard came back to a turn it had already taken.
+    LoopDetected,
+}
+
+impl<'a> PatrolSimulator<'a> {
+    const GUARD_NORTH: char = '^';
+    const GUARD_SOUTH: char = 'v';
+    const GUARD_WEST: char = '<';
+    const GUARD_EAST: char = '>';
+    const OBSTACLES: [char; 2] = ['#', 'O'];
+    const PATH_TURN: char = '+';
+    const PATH_VERTICAL: char = '|';
+    const PATH_HORIZONTAL: char = '-';
+
+    const PATH_CHARS: [char; 3] = [Self::PATH_TURN, Self::PATH_VERTICAL, Self::PATH_HORIZONTAL];
+
+    const GUARD_CHARS: [char; 4] = [
+        Self::GUARD_NORTH,
+        Self::GUARD_EAST,
+        Self::GUARD_SOUTH,
+        Self::GUARD_WEST,
+    ];
+
+    /// Fails if the grid has no guard on it.
+    pub fn new(grid: Grid) -> Result<Self> {
+        let mut sim = PatrolSimulator {
+            grid,
+            guard_position: (0, 0),
+            frames: None,
+        };
+
+        sim.guard_position = sim.find_guard_position()?;
+
+        Ok(sim)
+    }
+
+    /// Snapshots the grid to `frames` after every step of the simulation.
+    pub fn record_to(mut self, frames: &'a mut FrameRecorder) -> Self {
+        frames.snapshot(&self.grid);
+        self.frames = Some(frames);
+        self
+    }
+
+    /// Runs the patrol until the guard leaves the lab or walks in a loop.
+    pub fn simulate(&mut self) -> Result<Simulation> {
+        let mut rotated_last_turn = false;
+        let mut previous_char: Option<char> = None;
+
+        while let Some((nx, ny)) = self.get_next_position() {
+            let next_char = self.grid.get(nx, ny);
+
+            if Self::OBSTACLES.contains(&next_char) {
+                self.rotate_guard()?;
+                self.record_frame();
+                rotated_last_turn = true;
+                continue;
+            }
+
+            if rotated_last_turn && previous_char.map(|c| c == Self::PATH_TURN).unwrap_or(false) {
+                return Ok(Simulation::LoopDetected);
+            }
+
+            self.move_guard(nx, ny, rotated_last_turn)?;
+            self.record_frame();
+
+            rotated_last_turn = false;
+            previous_char = Some(next_char);
+        }
+
+        Ok(Simulation::DistinctPositions(self.path_len()))
+    }
+
+    pub fn guard_position(&self) -> (usize, usize) {
+        self.guard_position
+    }
+
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn record_frame(&mut self) {
+        if let Some(frames) = self.frames.as_deref_mut() {
+            frames.snapshot(&self.grid);
+        }
+    }
+
+    fn path_len(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|&c| Self::PATH_CHARS.contains(c) || Self::GUARD_CHARS.contains(c))
+            .count()
+    }
+
+    fn move_guard(&mut self, x: usize, y: usize, rotated_last_turn: bool) -> Result<()> {
+        let (px, py) = self.guard_position;
+        let guard_char = self.grid.get(px, py);
+        let path_char = self.get_path_character(guard_char, rotated_last_turn)?;
+
+        self.grid.set(px, py, path_char);
+        self.grid.set(x, y, guard_char);
+        self.guard_position = (x, y);
+
+        Ok(())
+    }
+
+    fn get_path_character(&self, guard_char: char, rotated_last_turn: bool) -> Result<char> {
+        if rotated_last_turn {
+            return Ok(Self::PATH_TURN);
+        }
+
+        match guard_char {
+            Self::GUARD_NORTH | Self::GUARD_SOUTH => Ok(Self::PATH_VERTICAL),
+            Self::GUARD_EAST | Self::GUARD_WEST => Ok(Self::PATH_HORIZONTAL),
+            _ => Err("Invalid guard character".into()),
+        }
+    }
+
+    fn rotate_guard(&mut self) -> Result<()> {
+        let (gx, gy) = self.guard_position;
+        let guard_char = self.grid.get(gx, gy);
+
+        let rotation_index = Self::GUARD_CHARS
+            .iter()
+            .position(|&c| c == guard_char)
+            .ok_or("Invalid guard character")?;
+
+        let next_char = Self::GUARD_CHARS[(rotation_index + 1) % Self::GUARD_CHARS.len()];
+        self.grid.set(gx, gy, next_char);
+
+        Ok(())
+    }
+
+    fn find_guard_position(&self) -> Result<(usize, usize)> {
+        Ok(self
+            .grid
+            .coordinates_iter()
+            .find(|(x, y)| Self::GUARD_CHARS.contains(&self.grid.get(*x, *y)))
+            .ok_or("Guard position not found")?)
+    }
+
+    fn get_next_position(&self) -> Option<(usize, usize)> {
+        let mut dx: i32 = 0;
+        let mut dy: i32 = 0;
+        let guard_char = self.grid.get(self.guard_position.0, self.guard_position.1);
+        let (gx, gy) = (self.guard_position.0 as i32, self.guard_position.1 as i32);
+
+        match guard_char {
+            Self::GUARD_NORTH => dy = -1,
+            Self::GUARD_SOUTH => dy = 1,
+            Self::GUARD_WEST => dx = -1,
+            Self::GUARD_EAST => dx = 1,
+            _ => return None,
+        }
+
+        let (nx, ny) = (gx + dx, gy + dy);
+
+        if (nx < 0 || nx >= self.grid.width() as i32) || (ny < 0 || ny >= self.grid.height() as i32)
+        {
+            return None;
+        }
+
+        Some((nx as usize, ny as usize))
+    }
+}
+
+impl Solution for GuardGallivant {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(grid: &Grid) -> Result<Answer> {
+        Ok(part1(grid.clone())?.into())
+    }
+
+    fn part2(grid: &Grid) -> Result<Answer> {
+        Ok(part2(grid)?.into())
+    }
+
+    fn record(grid: &Grid, part: Part, recorder: &mut FrameRecorder) -> Result<()> {
+        match part {
+            Part::One => {
+                PatrolSimulator::new(grid.clone())?
+                    .record_to(recorder)
+                    .simulate()?;
+            }
+            Part::Two => {
+                // One frame per obstacle that traps the guard
+                let mut grid = grid.clone();
+                recorder.snapshot(&grid);
+
+                for (x, y) in loop_obstacles(&grid)? {
+                    grid.set(x, y, 'O');
+                    recorder.snapshot(&grid);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<GuardGallivant>(env!("CARGO_MANIFEST_DIR"));
+}
+
+/// Number of cells the guard visits before leaving the lab.
+pub fn part1(grid: Grid) -> Result<usize> {
+    let mut sim = PatrolSimulator::new(grid)?;
+
+    match sim.simulate()? {
+        Simulation::DistinctPositions(distinct_positions) => Ok(distinct_positions),
+        Simulation::LoopDetected => {
+            Err("The guard walks in a loop without any obstacle added".into())
+        }
+    }
+}
+
+/// Number of positions where a new obstacle traps the guard in a loop.
+pub fn part2(grid: &Grid) -> Result<usize> {
+    Ok(loop_obstacles(grid)?.len())
+}
+
+/// Positions where an added obstacle makes the guard walk in a loop.
+pub fn loop_obstacles(grid: &Grid) -> Result<Vec<(usize, usize)>> {
+    let progress = Progress::new("Placing obstacles", grid.size() as u64);
+    let coordinates = grid.coordinates_iter().collect::<Vec<_>>();
+
+    let causes_loop = parallel::map(&coordinates, |&(x, y)| {
+        aoc24::trace!("Checking ({}, {})", x, y);
+        progress.inc(1);
+
+        let grid_clone = grid.clone();
+        let mut sim = PatrolSimulator::new(grid_clone)?;
+
+        if sim.guard_position == (x, y) {
+            return Ok(false);
+        }
+
+        sim.grid.set(x, y, 'O');
+
+        Ok(matches!(sim.simulate()?, Simulation::LoopDetected))
+    });
+
+    let causes_loop = causes_loop.into_iter().collect::<Result<Vec<bool>>>()?;
+
+    Ok(coordinates
+        .into_iter()
+        .zip(causes_loop)
+        .filter(|&(_, is_loop)| is_loop)
+        .map(|(position, _)| position)
+        .collect())
+}
@@ -0,0 +1 @@
+aoc24::answer_tests!(d6_guard_gallivant::GuardGallivant);
@@ -0,0 +1,20 @@
+use d6_guard_gallivant::{GuardGallivant, PatrolSimulator};
+
+#[test]
+fn path_map() {
+    let mut sim = PatrolSimulator::new(aoc24::snapshot_example!(GuardGallivant)).unwrap();
+    sim.simulate().unwrap();
+
+    aoc24::assert_snapshot!("path_map", sim.grid());
+}
+
+#[test]
+fn loop_obstacles() {
+    let mut grid = aoc24::snapshot_example!(GuardGallivant);
+
+    for (x, y) in d6_guard_gallivant::loop_obstacles(&grid).unwrap() {
+        grid.set(x, y, 'O');
+    }
+
+    aoc24::assert_snapshot!("loop_obstacles", grid);
+}
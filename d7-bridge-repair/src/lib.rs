@@ -0,0 +1,217 @@
+//! Day 7: finds the calibration equations that some choice of operators makes true.
+
+use std::fmt;
+
+use aoc24::answer::Answer;
+use aoc24::parallel;
+use aoc24::parse::{self, ParseError, Span};
+use aoc24::progress::Progress;
+use aoc24::registry::Registry;
+use aoc24::solution::Solution;
+use aoc24::Result;
+use itertools::Itertools;
+
+pub struct BridgeRepair;
+
+/// Test value and the operands that should produce it, such as `190: 10 19`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Equation {
+    test_value: i64,
+    operands: Vec<i64>,
+}
+
+impl Equation {
+    pub const OPERATOR_ADD: &str = "+";
+    pub const OPERATOR_MULTIPLY: &str = "*";
+    pub const OPERATOR_CONCAT: &str = "||";
+
+    pub const OPERATORS_PART_1: [&str; 2] = [Self::OPERATOR_ADD, Self::OPERATOR_MULTIPLY];
+
+    pub const OPERATORS_PART_2: [&str; 3] = [
+        Self::OPERATOR_ADD,
+        Self::OPERATOR_MULTIPLY,
+        Self::OPERATOR_CONCAT,
+    ];
+
+    pub fn test_value(&self) -> i64 {
+        self.test_value
+    }
+
+    pub fn operands(&self) -> &[i64] {
+        &self.operands
+    }
+
+    /// Whether any combination of `operators`, evaluated left to right, gives the test value.
+    pub fn is_valid(&self, operators: &[&str]) -> Result<bool> {
+        let num_operators = self.operands.len() - 1;
+
+        let operator_permutations = (0..num_operators)
+            .map(|_| operators.iter().copied())
+            .multi_cartesian_product();
+
+        for operators in operator_permutations {
+            if self.is_valid_with_operators(&operators)? {
+                aoc24::debug!("{} \u{2713}", self.format_with_operators(&operators));
+                return Ok(true);
+            }
+        }
+
+        aoc24::debug!("{}: {:?} \u{2717}", self.test_value, self.operands);
+
+        Ok(false)
+    }
+
+    fn format_with_operators(&self, operators: &[&str]) -> String {
+        let mut result = format!("{}: ", self.test_value);
+
+        for (i, operand) in self.operands.iter().enumerate() {
+            result.push_str(&operand.to_string());
+
+            if i < operators.len() {
+                result.push_str(&format!(" {} ", operators[i]));
+            }
+        }
+
+        result
+    }
+
+    fn is_valid_with_operators(&self, operators: &[&str]) -> Result<bool> {
+        // Operators are evaluated from left to right not in normal order of operations
+        if operators.is_empty() {
+            return Ok(self.test_value == self.operands[0]);
+        }
+
+        let lhs = self.operands[0];
+        let rhs = self.operands[1];
+        let operator = operators[0];
+
+        let result = match operator {
+            Self::OPERATOR_ADD => lhs + rhs,
+            Self::OPERATOR_MULTIPLY => lhs * rhs,
+            Self::OPERATOR_CONCAT => {
+                let mut result = lhs.to_string();
+                result.push_str(&rhs.to_string());
+                result
+                    .parse::<i64>()
+                    .map_err(|e| format!("{} || {}: {}", lhs, rhs, e))?
+            }
+            _ => return Err("Invalid operator".into()),
+        };
+
+        let new_operators = &operators[1..];
+        let mut new_operands = self.operands[2..].to_vec();
+
+        new_operands.insert(0, result);
+
+        let new_equation = Equation {
+            test_value: self.test_value,
+            operands: new_operands.to_vec(),
+        };
+
+        new_equation.is_valid_with_operators(new_operators)
+    }
+}
+
+impl fmt::Display for Equation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.test_value, self.operands.iter().join(" "))
+    }
+}
+
+impl TryFrom<Span<'_>> for Equation {
+    type Error = ParseError;
+
+    fn try_from(line: Span) -> parse::Result<Self> {
+        let (test_value, operands_span) = line.split_once(":")?;
+        let operands = operands_span.parse_words()?;
+
+        if operands.is_empty() {
+            return Err(operands_span.error("expected at least one operand"));
+        }
+
+        Ok(Equation {
+            test_value: test_value.trim().parse()?,
+            operands,
+        })
+    }
+}
+
+impl Solution for BridgeRepair {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Vec<Equation>> {
+        Ok(parse::lines(input, Equation::try_from)?)
+    }
+
+    fn part1(equations: &Vec<Equation>) -> Result<Answer> {
+        Ok(calibrate(equations, &Equation::OPERATORS_PART_1)?.into())
+    }
+
+    fn part2(equations: &Vec<Equation>) -> Result<Answer> {
+        Ok(calibrate(equations, &Equation::OPERATORS_PART_2)?.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<BridgeRepair>(env!("CARGO_MANIFEST_DIR"));
+}
+
+/// Sum of the test values of the equations `operators` can make true.
+pub fn calibrate(equations: &[Equation], operators: &[&str]) -> Result<i64> {
+    let progress = Progress::new("Checking equations", equations.len() as u64);
+
+    let is_valid = parallel::map(equations, |e| {
+        progress.inc(1);
+        e.is_valid(operators)
+    });
+
+    let mut total = 0;
+
+    for (equation, is_valid) in equations.iter().zip(is_valid) {
+        if is_valid? {
+            total += equation.test_value;
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc24::testing;
+    use proptest::prelude::*;
+
+    fn equation() -> impl Strategy<Value = Equation> {
+        (0..i64::MAX, prop::collection::vec(0..1_000_000i64, 1..12)).prop_map(
+            |(test_value, operands)| Equation {
+                test_value,
+                operands,
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_equations_round_trip(equations in prop::collection::vec(equation(), 1..10)) {
+            let input = equations.iter().join("\n");
+
+            prop_assert_eq!(BridgeRepair::parse(&input).unwrap(), equations);
+        }
+
+        #[test]
+        fn test_equation_text_round_trip(line in "(0|[1-9][0-9]{0,17}): (0|[1-9][0-9]{0,5})( (0|[1-9][0-9]{0,5})){0,10}") {
+            let equation = Equation::try_from(Span::new(&line)).unwrap();
+
+            prop_assert_eq!(equation.to_string(), line);
+        }
+    }
+
+    #[test]
+    fn test_parse_no_panic() {
+        testing::check_parse_no_panic::<BridgeRepair>("[0-9: \n-]{0,30}");
+    }
+}
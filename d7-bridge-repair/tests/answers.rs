@@ -0,0 +1 @@
+aoc24::answer_tests!(d7_bridge_repair::BridgeRepair);
@@ -0,0 +1 @@
+aoc24::answer_tests!(d8_resonant_collinearity::ResonantCollinearity);
@@ -0,0 +1,22 @@
+use d8_resonant_collinearity::{FrequencyAnalyzer, FrequencyAnalyzerOptions, ResonantCollinearity};
+
+fn analyze(has_resonant_harmonics_detector: bool) -> FrequencyAnalyzer {
+    let options = FrequencyAnalyzerOptions::builder()
+        .has_resonant_harmonics_detector(has_resonant_harmonics_detector)
+        .build();
+
+    let mut analyzer =
+        FrequencyAnalyzer::with_options(aoc24::snapshot_example!(ResonantCollinearity), options);
+    analyzer.analyze();
+    analyzer
+}
+
+#[test]
+fn anti_nodes() {
+    aoc24::assert_snapshot!("anti_nodes", analyze(false).anti_nodes_grid());
+}
+
+#[test]
+fn anti_nodes_with_harmonics() {
+    aoc24::assert_snapshot!("anti_nodes_with_harmonics", analyze(true).anti_nodes_grid());
+}
@@ -0,0 +1,317 @@
+//! Day 9: compacts an amphipod's disk, block by block or whole files at a time.
+
+use std::fmt;
+
+use aoc24::answer::Answer;
+use aoc24::frames::FrameRecorder;
+use aoc24::grid::Grid;
+use aoc24::parse::{self, ParseError, Span};
+use aoc24::registry::Registry;
+use aoc24::solution::{Part, Solution};
+use aoc24::Result;
+
+pub struct DiskFragmenter;
+
+/// Block by block layout of the disk, `None` for free space.
+#[derive(Default, Clone)]
+pub struct Disk {
+    data: Vec<Option<u32>>,
+    max_block_id: u32,
+}
+
+impl Disk {
+    /// Moves whole files to the leftmost free span that fits them, highest file ID first.
+    pub fn compress_files(&mut self, mut frames: Option<&mut FrameRecorder>) {
+        for file_id in (0..=self.max_block_id).rev() {
+            // Files of length 0 have no blocks to move
+            let Some(file_position) = self.find_file(file_id) else {
+                continue;
+            };
+            let file_len = self.calc_file_len(file_id, file_position);
+
+            if let Some(free_space) = self.find_free_space(file_len) {
+                if free_space > file_position {
+                    continue;
+                }
+
+                self.data[free_space..free_space + file_len].fill(Some(file_id));
+                self.data[file_position..file_position + file_len].fill(None);
+
+                if let Some(frames) = frames.as_deref_mut() {
+                    let moved = (free_space..free_space + file_len)
+                        .chain(file_position..file_position + file_len);
+                    frames.diff(moved.map(|i| (i, 0, Self::block_char(self.data[i]))));
+                }
+            }
+        }
+    }
+
+    /// Moves single blocks from the end of the disk into the leftmost free space.
+    pub fn compress_blocks(&mut self, mut frames: Option<&mut FrameRecorder>) {
+        while let Some(first_free) = self.find_fragmented_space() {
+            let last_block = self.find_last_block().unwrap();
+            self.data.swap(first_free, last_block);
+
+            if let Some(frames) = frames.as_deref_mut() {
+                frames
+                    .diff([first_free, last_block].map(|i| (i, 0, Self::block_char(self.data[i]))));
+            }
+        }
+    }
+
+    /// Single row with a character per block, file IDs wrap around after `z`.
+    pub fn to_grid(&self) -> Grid {
+        let mut grid = Grid::new(self.data.len(), 1);
+
+        for (i, &block) in self.data.iter().enumerate() {
+            grid.set(i, 0, Self::block_char(block));
+        }
+
+        grid
+    }
+
+    fn block_char(block: Option<u32>) -> char {
+        match block {
+            None => '.',
+            Some(file_id) => char::from_digit(file_id % 36, 36).unwrap(),
+        }
+    }
+
+    fn find_last_block(&self) -> Option<usize> {
+        self.data.iter().rposition(|c| c.is_some())
+    }
+
+    /// Sum of each block's position times its file ID.
+    pub fn calc_checksum(&self) -> usize {
+        let mut checksum = 0;
+
+        for (i, c) in self.data.iter().enumerate() {
+            if c.is_none() {
+                continue;
+            }
+
+            checksum += i * c.unwrap() as usize;
+        }
+
+        checksum
+    }
+
+    fn find_file(&self, file_id: u32) -> Option<usize> {
+        self.data.iter().position(|c| *c == Some(file_id))
+    }
+
+    fn calc_file_len(&self, file_id: u32, file_position: usize) -> usize {
+        self.data[file_position..]
+            .iter()
+            .take_while(|c| **c == Some(file_id))
+            .count()
+    }
+
+    fn find_free_space(&self, len: usize) -> Option<usize> {
+        let mut free_space = 0;
+        let mut free_space_start = 0;
+
+        for (i, c) in self.data.iter().enumerate() {
+            match c {
+                None => {
+                    free_space += 1;
+
+                    if free_space == len {
+                        return Some(free_space_start);
+                    }
+                }
+                Some(_) => {
+                    free_space = 0;
+                    free_space_start = i + 1;
+                }
+            }
+        }
+
+        None
+    }
+
+    fn find_fragmented_space(&self) -> Option<usize> {
+        let first_free = self.data.iter().position(|c| c.is_none())?;
+        let non_contiguous_block = self.data[first_free + 1..].iter().any(|&c| c.is_some());
+
+        if non_contiguous_block {
+            return Some(first_free);
+        }
+
+        None
+    }
+}
+
+impl fmt::Display for Disk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let data = self
+            .data
+            .iter()
+            .map(|e| match e {
+                None => ".".to_string(),
+                Some(v) => format!("[{}]", v),
+            })
+            .collect::<String>();
+
+        write!(f, "{}", data)
+    }
+}
+
+/// Dense disk map from the input, alternating file and free space lengths.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DiskMap {
+    data: Vec<char>,
+}
+
+impl DiskMap {
+    pub fn expand(&self) -> Disk {
+        let mut block_id = 0;
+        let mut result = Disk::default();
+
+        for i in 0..self.data.len() {
+            let is_block = i % 2 == 0;
+            let n = self.data[i].to_digit(10).unwrap() as usize;
+
+            let value = if is_block {
+                let v = Some(block_id);
+                block_id += 1;
+                v
+            } else {
+                None
+            };
+
+            result.data.extend(vec![value; n]);
+        }
+
+        result.max_block_id = block_id - 1;
+        result
+    }
+}
+
+impl fmt::Display for DiskMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.data.iter().collect::<String>())
+    }
+}
+
+impl TryFrom<Span<'_>> for DiskMap {
+    type Error = ParseError;
+
+    fn try_from(s: Span) -> parse::Result<Self> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(s.error("expected at least one file length"));
+        }
+
+        if let Some((i, c)) = s.as_str().char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(ParseError::new(
+                s.line(),
+                s.column() + i,
+                format!("invalid length `{}`", c),
+            ));
+        }
+
+        Ok(DiskMap {
+            data: s.as_str().chars().collect(),
+        })
+    }
+}
+
+impl Solution for DiskFragmenter {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input = Disk;
+
+    fn parse(input: &str) -> Result<Disk> {
+        Ok(DiskMap::try_from(Span::new(input))?.expand())
+    }
+
+    fn part1(disk: &Disk) -> Result<Answer> {
+        Ok(part1(disk.clone()).into())
+    }
+
+    fn part2(disk: &Disk) -> Result<Answer> {
+        Ok(part2(disk.clone()).into())
+    }
+
+    fn record(disk: &Disk, part: Part, recorder: &mut FrameRecorder) -> Result<()> {
+        let mut disk = disk.clone();
+        recorder.snapshot(&disk.to_grid());
+
+        match part {
+            Part::One => disk.compress_blocks(Some(recorder)),
+            Part::Two => disk.compress_files(Some(recorder)),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<DiskFragmenter>(env!("CARGO_MANIFEST_DIR"));
+}
+
+/// Checksum once the disk has been compacted block by block.
+pub fn part1(mut disk: Disk) -> usize {
+    disk.compress_blocks(None);
+    disk.calc_checksum()
+}
+
+/// Checksum once the disk has been compacted a file at a time.
+pub fn part2(mut disk: Disk) -> usize {
+    disk.compress_files(None);
+    disk.calc_checksum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc24::testing;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_zero_length_files() {
+        let disk_map = DiskMap::try_from(Span::new("01102")).unwrap();
+        let mut disk = disk_map.expand();
+
+        disk.compress_files(None);
+
+        assert_eq!(disk.data, [Some(1), None, Some(2), Some(2)]);
+        assert_eq!(disk.calc_checksum(), 10);
+    }
+
+    fn disk_map() -> impl Strategy<Value = DiskMap> {
+        prop::collection::vec(0..10u32, 1..60).prop_map(|lengths| DiskMap {
+            data: lengths
+                .into_iter()
+                .map(|length| char::from_digit(length, 10).unwrap())
+                .collect(),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_disk_map_round_trip(disk_map in disk_map()) {
+            let parsed = DiskMap::try_from(Span::new(&disk_map.to_string())).unwrap();
+
+            prop_assert_eq!(parsed, disk_map);
+        }
+
+        #[test]
+        fn test_disk_map_expand(lengths in "[0-9]{1,60}") {
+            let disk = DiskMap::try_from(Span::new(&lengths)).unwrap().expand();
+
+            prop_assert_eq!(
+                disk.data.len(),
+                lengths.chars().map(|c| c.to_digit(10).unwrap() as usize).sum::<usize>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_no_panic() {
+        testing::check_parse_no_panic::<DiskFragmenter>("[0-9a\n ]{0,30}");
+    }
+}
@@ -0,0 +1 @@
+aoc24::answer_tests!(d9_disk_fragmenter::DiskFragmenter);
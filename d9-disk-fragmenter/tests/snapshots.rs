@@ -0,0 +1,13 @@
+use d9_disk_fragmenter::DiskFragmenter;
+
+#[test]
+fn disk() {
+    let disk = aoc24::snapshot_example!(DiskFragmenter);
+    let mut blocks = disk.clone();
+    let mut files = disk.clone();
+
+    blocks.compress_blocks(None);
+    files.compress_files(None);
+
+    aoc24::assert_snapshot!("disk", format!("{}\n{}\n{}\n", disk, blocks, files));
+}